@@ -0,0 +1,217 @@
+use crate::date_range::DateRange;
+use crate::query::{Operator, Query};
+
+/// Common Crawl index queried when the settings do not name another crawl.
+pub const COMMON_CRAWL_INDEX: &str = "CC-MAIN-2025-30";
+
+/// An archive index URL for the site/inurl/filetype part of a dork.
+pub struct ArchiveLookup {
+    pub label: String,
+    pub url: String,
+}
+
+/// Builds one Wayback CDX and one Common Crawl index URL per `site:` of the query, filtered by
+/// its `inurl:` and `filetype:`/`ext:` clauses and its date range. Other operators have no
+/// equivalent in a URL index and are ignored. Without a `site:` there is nothing to look up.
+pub fn lookups(query: &Query, common_crawl_index: &str) -> Vec<ArchiveLookup> {
+    let clauses = query.clauses();
+    let values = |operators: &[Operator], negated: bool| -> Vec<String> {
+        clauses
+            .iter()
+            .filter(|clause| clause.negated == negated)
+            .filter(|clause| clause.operator.is_some_and(|op| operators.contains(&op)))
+            .map(|clause| clause.term.text())
+            .filter(|value| !value.is_empty())
+            .collect()
+    };
+
+    let sites = values(&[Operator::Site], false);
+    let mut filters = vec![];
+    for negated in [false, true] {
+        let inurl = values(&[Operator::InUrl], negated);
+        if !inurl.is_empty() {
+            let pattern = format!(".*({}).*", alternatives(&inurl));
+            filters.push(Filter {
+                field: Field::Url,
+                pattern,
+                negated,
+            });
+        }
+        let extensions = values(&[Operator::FileType, Operator::Ext], negated);
+        if !extensions.is_empty() {
+            filters.push(extension_filter(&extensions, negated));
+        }
+    }
+    let dates = DateRange::from_query(query).unwrap_or_default();
+    let index = if common_crawl_index.trim().is_empty() {
+        COMMON_CRAWL_INDEX
+    } else {
+        common_crawl_index.trim()
+    };
+
+    let mut lookups = vec![];
+    for site in &sites {
+        let (url, match_type) = match_type(site);
+        lookups.push(ArchiveLookup {
+            label: format!("Wayback : {}", site),
+            url: cdx_url(
+                "https://web.archive.org/cdx/search/cdx",
+                &url,
+                match_type,
+                &filters,
+                &dates,
+                Field::name_wayback,
+                true,
+            ),
+        });
+        lookups.push(ArchiveLookup {
+            label: format!("Common Crawl {} : {}", index, site),
+            url: cdx_url(
+                &format!("https://index.commoncrawl.org/{}-index", index),
+                &url,
+                match_type,
+                &filters,
+                &dates,
+                Field::name_common_crawl,
+                false,
+            ),
+        });
+    }
+    lookups
+}
+
+#[derive(Clone, Copy)]
+enum Field {
+    Url,
+    Mime,
+}
+
+impl Field {
+    fn name_wayback(self) -> &'static str {
+        match self {
+            Field::Url => "original",
+            Field::Mime => "mimetype",
+        }
+    }
+
+    fn name_common_crawl(self) -> &'static str {
+        match self {
+            Field::Url => "url",
+            Field::Mime => "mime",
+        }
+    }
+}
+
+/// A regex `filter=` parameter, inverted with a leading `!` when negated.
+struct Filter {
+    field: Field,
+    pattern: String,
+    negated: bool,
+}
+
+/// `example.com` matches the whole domain, `example.com/docs` everything under that path and
+/// `*.example.com` is spelled as a domain match.
+fn match_type(site: &str) -> (String, &'static str) {
+    let site = site
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_start_matches("*.")
+        .trim_start_matches('.')
+        .trim_end_matches('/');
+    if site.contains('/') {
+        (site.to_string(), "prefix")
+    } else {
+        (site.to_string(), "domain")
+    }
+}
+
+/// Filters on the MIME type when every extension has a known one, and on the end of the URL
+/// otherwise, since a single filter cannot mix both fields.
+fn extension_filter(extensions: &[String], negated: bool) -> Filter {
+    let extensions: Vec<String> = extensions
+        .iter()
+        .map(|ext| ext.trim_start_matches('.').to_lowercase())
+        .collect();
+    let mimes: Option<Vec<&str>> = extensions.iter().map(|ext| mime_type(ext)).collect();
+    match mimes {
+        Some(mimes) => Filter {
+            field: Field::Mime,
+            pattern: mimes.join("|"),
+            negated,
+        },
+        None => Filter {
+            field: Field::Url,
+            pattern: format!(r".*\.({})(\?.*)?", alternatives(&extensions)),
+            negated,
+        },
+    }
+}
+
+fn mime_type(extension: &str) -> Option<&'static str> {
+    Some(match extension {
+        "pdf" => "application/pdf",
+        "doc" => "application/msword",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "xls" => "application/vnd.ms-excel",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "ppt" => "application/vnd.ms-powerpoint",
+        "pptx" => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        "txt" | "log" => "text/plain",
+        "csv" => "text/csv",
+        "xml" => "(text|application)/xml",
+        "json" => "application/json",
+        "zip" => "application/zip",
+        "html" | "htm" => "text/html",
+        _ => return None,
+    })
+}
+
+/// Joins values into a regex alternation, escaping regex syntax in each of them.
+fn alternatives(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|value| {
+            value
+                .chars()
+                .flat_map(|c| "\\.*+?()[]{}|^$".contains(c).then_some('\\').into_iter().chain([c]))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
+fn cdx_url(
+    endpoint: &str,
+    url: &str,
+    match_type: &str,
+    filters: &[Filter],
+    dates: &DateRange,
+    field_name: fn(Field) -> &'static str,
+    collapse: bool,
+) -> String {
+    let mut params = vec![
+        ("url", url.to_string()),
+        ("matchType", match_type.to_string()),
+        ("output", "json".to_string()),
+    ];
+    for filter in filters {
+        let sign = if filter.negated { "!" } else { "" };
+        params.push(("filter", format!("{}{}:{}", sign, field_name(filter.field), filter.pattern)));
+    }
+    if let Some(after) = dates.after {
+        params.push(("from", after.format("%Y%m%d").to_string()));
+    }
+    if let Some(before) = dates.before {
+        params.push(("to", before.format("%Y%m%d").to_string()));
+    }
+    // One line per distinct URL rather than one per capture.
+    if collapse {
+        params.push(("collapse", "urlkey".to_string()));
+    }
+
+    let query: Vec<String> = params
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, urlencoding::encode(value)))
+        .collect();
+    format!("{}?{}", endpoint, query.join("&"))
+}
@@ -0,0 +1,363 @@
+use crate::options::{SearchOptions, TimeRange};
+use crate::results::SearchResult;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+
+/// Google's Custom Search JSON API.
+pub const ENDPOINT: &str = "https://www.googleapis.com/customsearch/v1";
+/// Results per request; the API refuses more.
+pub const PAGE_SIZE: u32 = 10;
+/// The API never returns results past the hundredth.
+pub const MAX_RESULTS: u32 = 100;
+
+/// Credentials and endpoint, stored in the settings file. The endpoint can point at a local mock
+/// server; it defaults to [`ENDPOINT`] when empty.
+#[derive(Default, Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct CseConfig {
+    pub api_key: String,
+    /// The search engine ID (`cx`) of the Programmable Search Engine.
+    pub cx: String,
+    pub endpoint: String,
+}
+
+/// One page of results, and the `start` of the next one when there is more.
+pub struct Page {
+    pub results: Vec<SearchResult>,
+    pub next_start: Option<u32>,
+}
+
+#[derive(Debug)]
+pub enum CseError {
+    MissingCredentials,
+    /// 429: too many requests per minute.
+    RateLimited(String),
+    /// 429 or 403 about the queries per day: the daily allowance is used up.
+    QuotaExceeded(String),
+    Http(u16, String),
+    Network(String),
+    InvalidResponse(String),
+}
+
+impl fmt::Display for CseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CseError::MissingCredentials => write!(f, "clé d'API et identifiant cx requis"),
+            CseError::RateLimited(message) => {
+                write!(f, "trop de requêtes, réessayez dans une minute ({})", message)
+            }
+            CseError::QuotaExceeded(message) => {
+                write!(f, "quota journalier de l'API épuisé ({})", message)
+            }
+            CseError::Http(status, message) => write!(f, "erreur HTTP {} : {}", status, message),
+            CseError::Network(message) => write!(f, "erreur réseau : {}", message),
+            CseError::InvalidResponse(message) => write!(f, "réponse illisible : {}", message),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Response {
+    #[serde(default)]
+    items: Vec<Item>,
+    #[serde(default)]
+    queries: Queries,
+}
+
+#[derive(Deserialize)]
+struct Item {
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    link: String,
+    #[serde(default)]
+    snippet: String,
+}
+
+#[derive(Default, Deserialize)]
+struct Queries {
+    #[serde(default, rename = "nextPage")]
+    next_page: Vec<NextPage>,
+}
+
+#[derive(Deserialize)]
+struct NextPage {
+    #[serde(rename = "startIndex")]
+    start_index: u32,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    error: ErrorBody,
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    errors: Vec<ErrorReason>,
+}
+
+#[derive(Deserialize)]
+struct ErrorReason {
+    #[serde(default)]
+    reason: String,
+}
+
+/// Fetches `num` results (at most [`PAGE_SIZE`]) starting at `start` (1-based) for an already
+/// translated query.
+pub fn search_page(
+    config: &CseConfig,
+    query: &str,
+    options: &SearchOptions,
+    start: u32,
+    num: u32,
+) -> Result<Page, CseError> {
+    if config.api_key.trim().is_empty() || config.cx.trim().is_empty() {
+        return Err(CseError::MissingCredentials);
+    }
+    let endpoint = match config.endpoint.trim() {
+        "" => ENDPOINT,
+        endpoint => endpoint,
+    };
+
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .http_status_as_error(false)
+        .timeout_global(Some(Duration::from_secs(30)))
+        .build()
+        .into();
+    let mut request = agent
+        .get(endpoint)
+        .query("key", config.api_key.trim())
+        .query("cx", config.cx.trim())
+        .query("q", query)
+        .query("start", start.to_string())
+        .query("num", num.clamp(1, PAGE_SIZE).to_string());
+    for (key, value) in params(options) {
+        request = request.query(key, value);
+    }
+
+    let mut response = request.call().map_err(|err| CseError::Network(err.to_string()))?;
+    let status = response.status().as_u16();
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|err| CseError::Network(err.to_string()))?;
+    if status != 200 {
+        return Err(status_error(status, &body));
+    }
+
+    let parsed: Response =
+        serde_json::from_str(&body).map_err(|err| CseError::InvalidResponse(err.to_string()))?;
+    let next_start = parsed
+        .queries
+        .next_page
+        .first()
+        .map(|next| next.start_index)
+        .filter(|next| *next + PAGE_SIZE - 1 <= MAX_RESULTS && !parsed.items.is_empty());
+    let results = parsed
+        .items
+        .into_iter()
+        .map(|item| SearchResult {
+            title: item.title,
+            link: item.link,
+            snippet: item.snippet.replace('\n', " "),
+        })
+        .collect();
+    Ok(Page { results, next_start })
+}
+
+fn status_error(status: u16, body: &str) -> CseError {
+    let (message, reasons) = match serde_json::from_str::<ErrorResponse>(body) {
+        Ok(parsed) => (
+            parsed.error.message,
+            parsed.error.errors.into_iter().map(|error| error.reason).collect(),
+        ),
+        Err(_) => (body.trim().to_string(), vec![]),
+    };
+    // The per minute and per day limits both answer 429 `RESOURCE_EXHAUSTED`; only the message
+    // tells which limit was hit.
+    let daily = message.to_lowercase().contains("per day")
+        || reasons.iter().any(|reason| reason.contains("dailyLimit"));
+    let quota = daily || reasons.iter().any(|reason| reason.contains("quota"));
+    match status {
+        429 if daily => CseError::QuotaExceeded(message),
+        429 => CseError::RateLimited(message),
+        403 if quota => CseError::QuotaExceeded(message),
+        _ => CseError::Http(status, message),
+    }
+}
+
+/// The search options the API accepts, under its own parameter names.
+fn params(options: &SearchOptions) -> Vec<(&'static str, String)> {
+    let mut params = vec![];
+    if !options.language.trim().is_empty() {
+        params.push(("hl", options.language.trim().to_string()));
+    }
+    if !options.region.trim().is_empty() {
+        params.push(("gl", options.region.trim().to_string()));
+    }
+    if !options.results_language.trim().is_empty() {
+        let lang = options.results_language.trim();
+        let lr = if lang.starts_with("lang_") { lang.to_string() } else { format!("lang_{}", lang) };
+        params.push(("lr", lr));
+    }
+    if options.no_filter {
+        params.push(("filter", "0".to_string()));
+    }
+    if options.safe_off {
+        params.push(("safe", "off".to_string()));
+    }
+    // The API has no hourly restriction; the last day is the closest.
+    let restrict = match options.time_range {
+        TimeRange::Any => None,
+        TimeRange::Hour | TimeRange::Day => Some("d1"),
+        TimeRange::Week => Some("w1"),
+        TimeRange::Month => Some("m1"),
+        TimeRange::Year => Some("y1"),
+    };
+    if let Some(restrict) = restrict {
+        params.push(("dateRestrict", restrict.to_string()));
+    }
+    params
+}
+
+/// Fetches up to `max_results` results page by page, handing each page to `on_page` as it
+/// arrives. Stops at the first error, after the pages already handed over.
+pub fn search(
+    config: &CseConfig,
+    query: &str,
+    options: &SearchOptions,
+    max_results: u32,
+    on_page: &mut dyn FnMut(Vec<SearchResult>),
+) -> Result<(), CseError> {
+    let limit = max_results.min(MAX_RESULTS);
+    let mut start = 1;
+    while start <= limit {
+        let page = search_page(config, query, options, start, limit - start + 1)?;
+        on_page(page.results);
+        match page.next_start {
+            Some(next) if next > start => start = next,
+            _ => break,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// Serves each request with `respond(path)`, a status and a JSON body, and records the paths.
+    fn mock_server(respond: fn(&str) -> (u16, String)) -> (CseConfig, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}/customsearch/v1", listener.local_addr().unwrap());
+        let paths = Arc::new(Mutex::new(vec![]));
+        let recorded = Arc::clone(&paths);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut header = String::new();
+                while reader.read_line(&mut header).unwrap() > 2 {
+                    header.clear();
+                }
+                let path = request_line.split_whitespace().nth(1).unwrap_or_default().to_string();
+                let (status, body) = respond(&path);
+                recorded.lock().unwrap().push(path);
+                let head = format!(
+                    "HTTP/1.1 {} X\r\nContent-Type: application/json\r\nContent-Length: {}\r\n",
+                    status,
+                    body.len()
+                );
+                write!(stream, "{}Connection: close\r\n\r\n{}", head, body).unwrap();
+            }
+        });
+        let config = CseConfig {
+            api_key: "key".to_string(),
+            cx: "cx".to_string(),
+            endpoint,
+        };
+        (config, paths)
+    }
+
+    fn param(path: &str, name: &str) -> u32 {
+        path.split(['?', '&'])
+            .find_map(|pair| pair.strip_prefix(&format!("{}=", name)))
+            .and_then(|value| value.parse().ok())
+            .unwrap()
+    }
+
+    fn error_body(message: &str, reason: &str) -> String {
+        serde_json::json!({ "error": { "message": message, "errors": [{ "reason": reason }] } }).to_string()
+    }
+
+    #[test]
+    fn pages_until_max_results() {
+        let (config, paths) = mock_server(|path| {
+            let (start, num) = (param(path, "start"), param(path, "num"));
+            let items: Vec<_> = (start..start + num)
+                .map(|i| serde_json::json!({ "title": format!("r{}", i), "link": format!("https://r{}.example", i) }))
+                .collect();
+            let next = serde_json::json!({ "nextPage": [{ "startIndex": start + num }] });
+            (200, serde_json::json!({ "items": items, "queries": next }).to_string())
+        });
+        let mut results = vec![];
+        search(&config, "site:example.com", &SearchOptions::default(), 25, &mut |page| {
+            results.extend(page)
+        })
+        .unwrap();
+
+        assert_eq!(results.len(), 25);
+        assert_eq!(results[24].link, "https://r25.example");
+        let pages: Vec<(u32, u32)> = paths
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|path| (param(path, "start"), param(path, "num")))
+            .collect();
+        assert_eq!(pages, vec![(1, 10), (11, 10), (21, 5)]);
+    }
+
+    #[test]
+    fn rate_limit_and_daily_quota_are_told_apart() {
+        let (config, _) = mock_server(|_| {
+            (429, error_body("Quota exceeded for quota metric 'Queries' and limit 'Queries per minute'", "rateLimitExceeded"))
+        });
+        let result = search_page(&config, "a", &SearchOptions::default(), 1, PAGE_SIZE);
+        assert!(matches!(result, Err(CseError::RateLimited(_))));
+
+        let (config, _) = mock_server(|_| {
+            (429, error_body("Quota exceeded for quota metric 'Queries' and limit 'Queries per day'", "rateLimitExceeded"))
+        });
+        let result = search_page(&config, "a", &SearchOptions::default(), 1, PAGE_SIZE);
+        assert!(matches!(result, Err(CseError::QuotaExceeded(_))));
+
+        let (config, _) = mock_server(|_| (403, error_body("Daily Limit Exceeded", "dailyLimitExceeded")));
+        let result = search_page(&config, "a", &SearchOptions::default(), 1, PAGE_SIZE);
+        assert!(matches!(result, Err(CseError::QuotaExceeded(_))));
+    }
+
+    #[test]
+    fn errors_stop_paging_after_the_pages_received() {
+        let (config, _) = mock_server(|path| match param(path, "start") {
+            1 => {
+                let next = serde_json::json!({ "nextPage": [{ "startIndex": 11 }] });
+                (200, serde_json::json!({ "items": [{ "link": "https://a.example" }], "queries": next }).to_string())
+            }
+            _ => (429, error_body("Queries per minute", "rateLimitExceeded")),
+        });
+        let mut results = vec![];
+        let result = search(&config, "a", &SearchOptions::default(), 30, &mut |page| results.extend(page));
+        assert!(matches!(result, Err(CseError::RateLimited(_))));
+        assert_eq!(results.len(), 1);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::query::{Operator, Query};
+use chrono::NaiveDate;
+use std::fmt;
+
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+
+#[derive(Debug)]
+pub enum DateRangeError {
+    Invalid(Operator, String),
+    Unordered { after: NaiveDate, before: NaiveDate },
+}
+
+impl fmt::Display for DateRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateRangeError::Invalid(operator, value) => {
+                write!(f, "{}:{} n'est pas une date AAAA-MM-JJ", operator, value)
+            }
+            DateRangeError::Unordered { after, before } => {
+                write!(f, "after:{} doit précéder before:{}", after, before)
+            }
+        }
+    }
+}
+
+/// The period set by the top-level `after:`/`before:` clauses of a query.
+#[derive(Default, Debug)]
+pub struct DateRange {
+    pub after: Option<NaiveDate>,
+    pub before: Option<NaiveDate>,
+}
+
+/// Accepts `2020-05-31` as well as the year-only `2020` that Google also understands.
+pub fn parse_date(value: &str) -> Option<NaiveDate> {
+    let value = value.trim();
+    NaiveDate::parse_from_str(value, DATE_FORMAT).ok().or_else(|| {
+        let year = value.parse().ok().filter(|_| value.len() == 4)?;
+        NaiveDate::from_ymd_opt(year, 1, 1)
+    })
+}
+
+impl DateRange {
+    pub fn from_query(query: &Query) -> Result<Self, DateRangeError> {
+        let mut range = DateRange::default();
+        for (operator, value) in top_level(query).iter().filter_map(date_clause) {
+            let date = parse_date(&value).ok_or(DateRangeError::Invalid(operator, value))?;
+            // Several bounds on the same side narrow the period down.
+            if operator == Operator::After {
+                range.after = range.after.max(Some(date));
+            } else {
+                range.before = Some(range.before.map_or(date, |before| before.min(date)));
+            }
+        }
+
+        if let (Some(after), Some(before)) = (range.after, range.before)
+            && after >= before
+        {
+            return Err(DateRangeError::Unordered { after, before });
+        }
+        Ok(range)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.after.is_none() && self.before.is_none()
+    }
+
+    /// Google's custom date range parameter, e.g. `cdr:1,cd_min:1/1/2020,cd_max:12/31/2020`.
+    pub fn tbs(&self) -> String {
+        let mut tbs = String::from("cdr:1");
+        if let Some(after) = self.after {
+            tbs.push_str(&format!(",cd_min:{}", after.format("%-m/%-d/%Y")));
+        }
+        if let Some(before) = self.before {
+            tbs.push_str(&format!(",cd_max:{}", before.format("%-m/%-d/%Y")));
+        }
+        tbs
+    }
+}
+
+/// Removes the top-level `after:`/`before:` clauses so that they can be sent as URL parameters instead.
+pub fn without_dates(query: &Query) -> Query {
+    Query::all(
+        top_level(query)
+            .iter()
+            .filter(|child| date_clause(child).is_none())
+            .cloned()
+            .collect(),
+    )
+}
+
+fn top_level(query: &Query) -> &[Query] {
+    match query {
+        Query::And(children) => children,
+        other => std::slice::from_ref(other),
+    }
+}
+
+fn date_clause(query: &Query) -> Option<(Operator, String)> {
+    match query {
+        Query::Clause(clause) if !clause.negated => match clause.operator {
+            Some(operator @ (Operator::After | Operator::Before)) => Some((operator, clause.term.text())),
+            _ => None,
+        },
+        _ => None,
+    }
+}
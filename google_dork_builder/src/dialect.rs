@@ -0,0 +1,68 @@
+use crate::query::Operator;
+use serde::{Deserialize, Serialize};
+
+/// The query syntax a set of engines understands. It decides which operators the form offers and
+/// which engines can run the query.
+#[derive(Default, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Dialect {
+    /// Google style operators, understood to some degree by every web search engine.
+    #[default]
+    Web,
+    /// GitHub and GitLab code search.
+    Code,
+    /// Shodan style filters on banners of hosts exposed on the Internet, also rendered for Censys
+    /// and FOFA.
+    Device,
+}
+
+impl Dialect {
+    pub const ALL: &'static [Dialect] = &[Dialect::Web, Dialect::Code, Dialect::Device];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Dialect::Web => "Web",
+            Dialect::Code => "Code (GitHub, GitLab)",
+            Dialect::Device => "Appareils (Shodan, Censys, FOFA)",
+        }
+    }
+
+    pub fn operators(self) -> &'static [Operator] {
+        match self {
+            Dialect::Web => &[
+                Operator::Site,
+                Operator::InUrl,
+                Operator::InTitle,
+                Operator::FileType,
+                Operator::InText,
+                Operator::Ext,
+                Operator::AllInTitle,
+                Operator::AllInUrl,
+                Operator::AllInText,
+                Operator::InAnchor,
+                Operator::Related,
+                Operator::Cache,
+                Operator::Before,
+                Operator::After,
+                Operator::Range,
+            ],
+            Dialect::Code => &[
+                Operator::Repo,
+                Operator::Org,
+                Operator::Path,
+                Operator::Language,
+                Operator::Filename,
+                Operator::Extension,
+            ],
+            Dialect::Device => &[
+                Operator::Port,
+                Operator::Product,
+                Operator::HttpTitle,
+                Operator::Org,
+                Operator::Country,
+                Operator::Hostname,
+                Operator::Net,
+            ],
+        }
+    }
+}
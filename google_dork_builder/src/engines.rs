@@ -0,0 +1,579 @@
+use crate::date_range::{DateRange, parse_date};
+use crate::dialect::Dialect;
+use crate::options::{SearchOptions, TimeRange};
+use crate::query::{Clause, Operator};
+use base64::Engine;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+pub type UrlParams = Vec<(&'static str, String)>;
+
+pub trait SearchEngine {
+    fn name(&self) -> &str;
+
+    fn dialect(&self) -> Dialect {
+        Dialect::Web
+    }
+
+    /// Search URL up to and including the query parameter, e.g. `https://www.google.com/search?q=`.
+    fn base_url(&self) -> &str;
+
+    /// How this engine spells `operator`, or `None` when it has no such operator.
+    fn operator_name(&self, operator: Operator) -> Option<&str>;
+
+    /// The AND and OR glue between terms.
+    fn separators(&self) -> (&str, &str) {
+        (" ", " OR ")
+    }
+
+    /// Engine specific rewriting of a whole clause, tried before `operator_name`.
+    fn rewrite(&self, _clause: &Clause) -> Option<String> {
+        None
+    }
+
+    /// Whether `url_params` can restrict results to a custom date range.
+    fn handles_date_range(&self) -> bool {
+        false
+    }
+
+    /// URL parameters for `options`, and for `range` when the engine handles date ranges.
+    fn url_params(&self, _options: &SearchOptions, _range: Option<&DateRange>) -> UrlParams {
+        vec![]
+    }
+
+    /// The query as it goes into the URL, after `base_url`.
+    fn encode_query(&self, query: &str) -> String {
+        urlencoding::encode(query).into_owned()
+    }
+
+    fn search_url(&self, query: &str, params: &[(&str, String)]) -> String {
+        let mut url = format!("{}{}", self.base_url(), self.encode_query(query));
+        for (key, value) in params {
+            url.push_str(&format!("&{}={}", key, urlencoding::encode(value)));
+        }
+        url
+    }
+}
+
+pub struct Google;
+
+impl SearchEngine for Google {
+    fn name(&self) -> &str {
+        "Google"
+    }
+
+    fn base_url(&self) -> &str {
+        "https://www.google.com/search?q="
+    }
+
+    fn operator_name(&self, operator: Operator) -> Option<&str> {
+        Dialect::Web.operators().contains(&operator).then(|| operator.as_str())
+    }
+
+    fn handles_date_range(&self) -> bool {
+        true
+    }
+
+    fn url_params(&self, options: &SearchOptions, range: Option<&DateRange>) -> UrlParams {
+        let mut params = vec![];
+        push_non_empty(&mut params, "hl", &options.language);
+        push_non_empty(&mut params, "gl", &options.region);
+        if !options.results_language.is_empty() {
+            let lang = &options.results_language;
+            let lr = if lang.starts_with("lang_") { lang.clone() } else { format!("lang_{}", lang) };
+            params.push(("lr", lr));
+        }
+        if options.num > 0 {
+            params.push(("num", options.num.to_string()));
+        }
+        if options.no_filter {
+            params.push(("filter", "0".to_string()));
+        }
+        if options.safe_off {
+            params.push(("safe", "off".to_string()));
+        }
+
+        let mut tbs = vec![];
+        if options.verbatim {
+            tbs.push("li:1".to_string());
+        }
+        match (range, options.time_range.code()) {
+            (Some(range), _) => tbs.push(range.tbs()),
+            (None, Some(code)) => tbs.push(format!("qdr:{}", code)),
+            (None, None) => {}
+        }
+        if !tbs.is_empty() {
+            params.push(("tbs", tbs.join(",")));
+        }
+        params
+    }
+}
+
+pub struct Bing;
+
+impl SearchEngine for Bing {
+    fn name(&self) -> &str {
+        "Bing"
+    }
+
+    fn base_url(&self) -> &str {
+        "https://www.bing.com/search?q="
+    }
+
+    fn operator_name(&self, operator: Operator) -> Option<&str> {
+        match operator {
+            Operator::Site | Operator::InTitle | Operator::FileType | Operator::InAnchor => {
+                Some(operator.as_str())
+            }
+            Operator::InText => Some("inbody"),
+            Operator::InUrl => Some("instreamset:(url)"),
+            _ => None,
+        }
+    }
+
+    fn handles_date_range(&self) -> bool {
+        true
+    }
+
+    /// Bing's custom range counts days since the Unix epoch: `filters=ex1:"ez5_18262_18627"`.
+    fn url_params(&self, options: &SearchOptions, range: Option<&DateRange>) -> UrlParams {
+        let mut params = vec![];
+        push_non_empty(&mut params, "setlang", &options.language);
+        push_non_empty(&mut params, "cc", &options.region);
+        if options.num > 0 {
+            params.push(("count", options.num.to_string()));
+        }
+        if options.safe_off {
+            params.push(("adlt", "off".to_string()));
+        }
+
+        let filter = match (range, options.time_range) {
+            (Some(range), _) => {
+                let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap_or_default();
+                let days = |date: NaiveDate| (date - epoch).num_days();
+                let after = range.after.map_or(0, days);
+                let before = range.before.map_or_else(|| days(chrono::Local::now().date_naive()), days);
+                Some(format!("ez5_{}_{}", after, before))
+            }
+            (None, TimeRange::Day) => Some("ez1".to_string()),
+            (None, TimeRange::Week) => Some("ez2".to_string()),
+            (None, TimeRange::Month) => Some("ez3".to_string()),
+            (None, _) => None,
+        };
+        if let Some(filter) = filter {
+            params.push(("filters", format!("ex1:\"{}\"", filter)));
+        }
+        params
+    }
+}
+
+pub struct DuckDuckGo;
+
+impl SearchEngine for DuckDuckGo {
+    fn name(&self) -> &str {
+        "DuckDuckGo"
+    }
+
+    fn base_url(&self) -> &str {
+        "https://duckduckgo.com/?q="
+    }
+
+    fn operator_name(&self, operator: Operator) -> Option<&str> {
+        match operator {
+            Operator::Site | Operator::InTitle | Operator::FileType => Some(operator.as_str()),
+            Operator::InText => Some("inbody"),
+            _ => None,
+        }
+    }
+
+    fn url_params(&self, options: &SearchOptions, _range: Option<&DateRange>) -> UrlParams {
+        let mut params = vec![];
+        if !options.region.is_empty() {
+            let language = if options.language.is_empty() { &options.region } else { &options.language };
+            params.push(("kl", format!("{}-{}", options.region, language).to_lowercase()));
+        }
+        if options.safe_off {
+            params.push(("kp", "-2".to_string()));
+        }
+        if let Some(code) = options.time_range.code().filter(|_| options.time_range != TimeRange::Hour) {
+            params.push(("df", code.to_string()));
+        }
+        params
+    }
+}
+
+pub struct Yandex;
+
+impl SearchEngine for Yandex {
+    fn name(&self) -> &str {
+        "Yandex"
+    }
+
+    fn base_url(&self) -> &str {
+        "https://yandex.com/search/?text="
+    }
+
+    fn operator_name(&self, operator: Operator) -> Option<&str> {
+        match operator {
+            Operator::Site | Operator::InUrl => Some(operator.as_str()),
+            Operator::InTitle => Some("title"),
+            Operator::FileType => Some("mime"),
+            _ => None,
+        }
+    }
+
+    /// Yandex filters dates with `date:<20200101` and `date:>20200101`.
+    fn rewrite(&self, clause: &Clause) -> Option<String> {
+        let sign = match clause.operator? {
+            Operator::Before => '<',
+            Operator::After => '>',
+            _ => return None,
+        };
+        let date = parse_date(&clause.term.text())?;
+        let negation = if clause.negated { "-" } else { "" };
+        Some(format!("{}date:{}{}", negation, sign, date.format("%Y%m%d")))
+    }
+
+    fn url_params(&self, options: &SearchOptions, _range: Option<&DateRange>) -> UrlParams {
+        let mut params = vec![];
+        push_non_empty(&mut params, "lang", &options.language);
+        if options.num > 0 {
+            params.push(("numdoc", options.num.to_string()));
+        }
+        params
+    }
+}
+
+pub struct Startpage;
+
+impl SearchEngine for Startpage {
+    fn name(&self) -> &str {
+        "Startpage"
+    }
+
+    fn base_url(&self) -> &str {
+        "https://www.startpage.com/do/search?query="
+    }
+
+    fn operator_name(&self, operator: Operator) -> Option<&str> {
+        match operator {
+            Operator::Site
+            | Operator::InUrl
+            | Operator::InTitle
+            | Operator::FileType
+            | Operator::InText
+            | Operator::Range => Some(operator.as_str()),
+            _ => None,
+        }
+    }
+
+    fn url_params(&self, options: &SearchOptions, _range: Option<&DateRange>) -> UrlParams {
+        let mut params = vec![];
+        if options.safe_off {
+            params.push(("qadf", "none".to_string()));
+        }
+        if let Some(code) = options.time_range.code().filter(|_| options.time_range != TimeRange::Hour) {
+            params.push(("with_date", code.to_string()));
+        }
+        params
+    }
+}
+
+pub struct GitHub;
+
+impl SearchEngine for GitHub {
+    fn name(&self) -> &str {
+        "GitHub"
+    }
+
+    fn dialect(&self) -> Dialect {
+        Dialect::Code
+    }
+
+    fn base_url(&self) -> &str {
+        "https://github.com/search?q="
+    }
+
+    fn operator_name(&self, operator: Operator) -> Option<&str> {
+        match operator {
+            Operator::Repo | Operator::Org | Operator::Path | Operator::Language => Some(operator.as_str()),
+            _ => None,
+        }
+    }
+
+    /// GitHub code search dropped `filename:` and `extension:`; both are expressed with `path:`,
+    /// as an anchored regex and as a glob.
+    fn rewrite(&self, clause: &Clause) -> Option<String> {
+        let text = clause.term.text();
+        let path = match clause.operator? {
+            Operator::Filename => {
+                let escaped: String = text
+                    .chars()
+                    .flat_map(|c| "\\.*+?()[]{}|^$/".contains(c).then_some('\\').into_iter().chain([c]))
+                    .collect();
+                format!("/(^|\\/){}$/", escaped)
+            }
+            Operator::Extension => format!("*.{}", text.trim_start_matches('.')),
+            _ => return None,
+        };
+        let negation = if clause.negated { "-" } else { "" };
+        Some(format!("{}path:{}", negation, path))
+    }
+
+    fn url_params(&self, _options: &SearchOptions, _range: Option<&DateRange>) -> UrlParams {
+        vec![("type", "code".to_string())]
+    }
+}
+
+/// GitLab.com code search. `filename:`, `path:` and `extension:` need advanced search, which is
+/// enabled there.
+pub struct GitLab;
+
+impl SearchEngine for GitLab {
+    fn name(&self) -> &str {
+        "GitLab"
+    }
+
+    fn dialect(&self) -> Dialect {
+        Dialect::Code
+    }
+
+    fn base_url(&self) -> &str {
+        "https://gitlab.com/search?search="
+    }
+
+    fn operator_name(&self, operator: Operator) -> Option<&str> {
+        match operator {
+            Operator::Filename | Operator::Path | Operator::Extension => Some(operator.as_str()),
+            _ => None,
+        }
+    }
+
+    fn url_params(&self, _options: &SearchOptions, _range: Option<&DateRange>) -> UrlParams {
+        vec![("scope", "blobs".to_string())]
+    }
+}
+
+pub struct Shodan;
+
+impl SearchEngine for Shodan {
+    fn name(&self) -> &str {
+        "Shodan"
+    }
+
+    fn dialect(&self) -> Dialect {
+        Dialect::Device
+    }
+
+    fn base_url(&self) -> &str {
+        "https://www.shodan.io/search?query="
+    }
+
+    fn operator_name(&self, operator: Operator) -> Option<&str> {
+        Dialect::Device.operators().contains(&operator).then(|| operator.as_str())
+    }
+}
+
+pub struct Censys;
+
+impl SearchEngine for Censys {
+    fn name(&self) -> &str {
+        "Censys"
+    }
+
+    fn dialect(&self) -> Dialect {
+        Dialect::Device
+    }
+
+    fn base_url(&self) -> &str {
+        "https://search.censys.io/search?resource=hosts&q="
+    }
+
+    fn operator_name(&self, operator: Operator) -> Option<&str> {
+        match operator {
+            Operator::Port => Some("services.port"),
+            Operator::Product => Some("services.software.product"),
+            Operator::HttpTitle => Some("services.http.response.html_title"),
+            Operator::Org => Some("autonomous_system.name"),
+            Operator::Country => Some("location.country_code"),
+            Operator::Hostname => Some("dns.names"),
+            Operator::Net => Some("ip"),
+            _ => None,
+        }
+    }
+
+    fn separators(&self) -> (&str, &str) {
+        (" and ", " or ")
+    }
+
+    /// Censys negates with `not` rather than a leading `-`.
+    fn rewrite(&self, clause: &Clause) -> Option<String> {
+        if !clause.negated {
+            return None;
+        }
+        let positive = match clause.operator {
+            Some(operator) => format!("{}:{}", self.operator_name(operator)?, clause.term),
+            None => clause.term.to_string(),
+        };
+        Some(format!("not {}", positive))
+    }
+}
+
+/// FOFA compares every field with `=` or `!=` against a quoted value and takes the query base64
+/// encoded in the URL.
+pub struct Fofa;
+
+impl SearchEngine for Fofa {
+    fn name(&self) -> &str {
+        "FOFA"
+    }
+
+    fn dialect(&self) -> Dialect {
+        Dialect::Device
+    }
+
+    fn base_url(&self) -> &str {
+        "https://fofa.info/result?qbase64="
+    }
+
+    fn operator_name(&self, operator: Operator) -> Option<&str> {
+        match operator {
+            Operator::Port | Operator::Product | Operator::Org | Operator::Country => Some(operator.as_str()),
+            Operator::HttpTitle => Some("title"),
+            Operator::Hostname => Some("domain"),
+            Operator::Net => Some("ip"),
+            _ => None,
+        }
+    }
+
+    fn separators(&self) -> (&str, &str) {
+        (" && ", " || ")
+    }
+
+    /// Free text is searched as a quoted string, and excluded from the page body when negated.
+    fn rewrite(&self, clause: &Clause) -> Option<String> {
+        let value = clause.term.text().replace('"', "\\\"");
+        let name = match clause.operator {
+            Some(operator) => self.operator_name(operator)?,
+            None if clause.negated => "body",
+            None => return Some(format!("\"{}\"", value)),
+        };
+        let comparison = if clause.negated { "!=" } else { "=" };
+        Some(format!("{}{}\"{}\"", name, comparison, value))
+    }
+
+    fn encode_query(&self, query: &str) -> String {
+        let encoded = base64::engine::general_purpose::STANDARD.encode(query);
+        urlencoding::encode(&encoded).into_owned()
+    }
+}
+
+/// How a custom engine escapes the query before putting it in its URL template.
+#[derive(Default, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Encoding {
+    /// `%20` for spaces, as in a path or most query strings.
+    #[default]
+    Percent,
+    /// `+` for spaces, as sent by HTML forms.
+    Plus,
+    /// Inserted as typed.
+    None,
+}
+
+impl Encoding {
+    fn encode(self, text: &str) -> String {
+        match self {
+            Encoding::Percent => urlencoding::encode(text).into_owned(),
+            Encoding::Plus => text
+                .split(' ')
+                .map(|part| urlencoding::encode(part).into_owned())
+                .collect::<Vec<_>>()
+                .join("+"),
+            Encoding::None => text.to_string(),
+        }
+    }
+}
+
+/// An engine defined in the settings file, such as a SearxNG instance or an internal search
+/// appliance. `operators` lists the supported operators, either as `site` or, when the engine
+/// spells it differently, as `intext=inbody`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CustomEngine {
+    pub name: String,
+    /// Search URL where `{query}` stands for the query, e.g. `https://searx.example/search?q={query}`.
+    pub url: String,
+    #[serde(default)]
+    pub dialect: Dialect,
+    #[serde(default)]
+    pub encoding: Encoding,
+    #[serde(default)]
+    pub operators: Vec<String>,
+}
+
+impl SearchEngine for CustomEngine {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn dialect(&self) -> Dialect {
+        self.dialect
+    }
+
+    fn base_url(&self) -> &str {
+        self.url.split("{query}").next().unwrap_or_default()
+    }
+
+    fn operator_name(&self, operator: Operator) -> Option<&str> {
+        self.operators.iter().find_map(|entry| {
+            let (name, native) = entry.split_once('=').unwrap_or((entry, entry));
+            operator
+                .as_str()
+                .eq_ignore_ascii_case(name.trim())
+                .then(|| native.trim())
+        })
+    }
+
+    fn search_url(&self, query: &str, params: &[(&str, String)]) -> String {
+        let query = self.encoding.encode(query);
+        let mut url = if self.url.contains("{query}") {
+            self.url.replace("{query}", &query)
+        } else {
+            format!("{}{}", self.url, query)
+        };
+        for (key, value) in params {
+            let separator = if url.contains('?') { '&' } else { '?' };
+            url.push_str(&format!("{}{}={}", separator, key, self.encoding.encode(value)));
+        }
+        url
+    }
+}
+
+fn push_non_empty(params: &mut UrlParams, key: &'static str, value: &str) {
+    let value = value.trim();
+    if !value.is_empty() {
+        params.push((key, value.to_string()));
+    }
+}
+
+/// The built-in engines followed by the custom ones, skipping custom engines without a name or URL.
+pub fn all_engines(custom: &[CustomEngine]) -> Vec<Box<dyn SearchEngine>> {
+    let mut engines: Vec<Box<dyn SearchEngine>> = vec![
+        Box::new(Google),
+        Box::new(Bing),
+        Box::new(DuckDuckGo),
+        Box::new(Yandex),
+        Box::new(Startpage),
+        Box::new(GitHub),
+        Box::new(GitLab),
+        Box::new(Shodan),
+        Box::new(Censys),
+        Box::new(Fofa),
+    ];
+    for engine in custom {
+        if !engine.name.trim().is_empty() && !engine.url.trim().is_empty() {
+            engines.push(Box::new(engine.clone()));
+        }
+    }
+    engines
+}
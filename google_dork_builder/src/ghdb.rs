@@ -0,0 +1,277 @@
+use crate::dialect::Dialect;
+use crate::lint::{self, Severity};
+use crate::query::{Query, Term};
+use crate::{DorkData, DorkTemplate, parser, templates};
+use scraper::Html;
+use serde::Deserialize;
+use serde_json::Value;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// The page of an entry on Exploit-DB.
+pub const ENTRY_URL: &str = "https://www.exploit-db.com/ghdb/";
+
+/// The templates of a GHDB export, and the dorks that could not be turned into one.
+pub struct GhdbImport {
+    pub templates: Vec<DorkTemplate>,
+    pub failures: Vec<Failure>,
+}
+
+/// A dork left out of the import.
+pub struct Failure {
+    pub id: String,
+    pub dork: String,
+    pub reason: String,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.id.is_empty() {
+            write!(f, "GHDB-{} ", self.id)?;
+        }
+        write!(f, "« {} » : {}", self.dork, self.reason)
+    }
+}
+
+/// One entry as read from the export, before its dork is parsed.
+#[derive(Default)]
+struct Entry {
+    id: String,
+    dork: String,
+    category: String,
+    date: String,
+    author: String,
+}
+
+/// Reads a GHDB export: the `ghdb.xml` of the exploitdb repository, the JSON of the Exploit-DB
+/// site (a list of entries, or an object with a `data` list) or a CSV file with a header row.
+pub fn import_file(path: &Path) -> Result<GhdbImport, String> {
+    let content = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let entries = match extension.as_str() {
+        "xml" => read_xml(&content)?,
+        "json" => read_json(&content)?,
+        "csv" => read_csv(&content)?,
+        _ => return Err("format inconnu, CSV, XML ou JSON attendu".to_string()),
+    };
+    if entries.is_empty() {
+        return Err("aucune entrée trouvée dans le fichier".to_string());
+    }
+
+    let mut import = GhdbImport {
+        templates: vec![],
+        failures: vec![],
+    };
+    for entry in entries {
+        match template(&entry) {
+            Ok(template) => import.templates.push(template),
+            Err(reason) => import.failures.push(Failure {
+                id: entry.id,
+                dork: entry.dork,
+                reason,
+            }),
+        }
+    }
+    Ok(import)
+}
+
+fn template(entry: &Entry) -> Result<DorkTemplate, String> {
+    let query = check(&entry.dork)?;
+    let category = if entry.category.is_empty() { "GHDB".to_string() } else { entry.category.clone() };
+    let id = if entry.id.is_empty() {
+        format!("ghdb/{}", templates::slug(&entry.dork))
+    } else {
+        format!("ghdb/{}", entry.id)
+    };
+    Ok(DorkTemplate {
+        id,
+        name: format!("{} - {}", category, entry.dork),
+        category,
+        ghdb_id: entry.id.parse().ok(),
+        date: entry.date.clone(),
+        author: entry.author.clone(),
+        ..DorkTemplate::from_data(&DorkData::from_query(&query), Dialect::Web)
+    })
+}
+
+/// Parses a dork, refusing what the parser would only take by dropping or misreading part of it.
+fn check(dork: &str) -> Result<Query, String> {
+    if dork.is_empty() {
+        return Err("requête vide".to_string());
+    }
+    if !dork.matches('"').count().is_multiple_of(2) {
+        return Err("guillemets non fermés".to_string());
+    }
+    let mut depth = 0i32;
+    for c in dork.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => {}
+        }
+        if depth < 0 {
+            break;
+        }
+    }
+    if depth != 0 {
+        return Err("parenthèses non équilibrées".to_string());
+    }
+    // A `-` before a group or standing alone is read differently by each engine, so the meaning
+    // of the dork could come out inverted.
+    let unquoted: String = dork.split('"').step_by(2).collect::<Vec<_>>().join(" ");
+    if unquoted.contains("-(") {
+        return Err("négation d'un groupe entre parenthèses".to_string());
+    }
+    if unquoted.split_whitespace().any(|token| token == "-") {
+        return Err("signe - isolé".to_string());
+    }
+
+    let query = parser::parse(dork);
+    let clauses = query.clauses();
+    if clauses.is_empty() {
+        return Err("aucun terme reconnu".to_string());
+    }
+    // Unknown operators are kept by the parser as bare words.
+    for clause in clauses.iter().filter(|clause| clause.operator.is_none()) {
+        if let Term::Word(word) = &clause.term
+            && let Some((name, value)) = word.split_once(':')
+            && name.len() > 1
+            && name.chars().all(|c| c.is_ascii_alphabetic())
+            && !value.starts_with("//")
+        {
+            return Err(format!("opérateur inconnu {}:", name));
+        }
+    }
+    if let Some(error) = lint::lint(&query).into_iter().find(|lint| lint.severity == Severity::Error) {
+        return Err(error.message);
+    }
+    Ok(query)
+}
+
+#[derive(Deserialize)]
+struct XmlFile {
+    #[serde(default, rename = "entry")]
+    entries: Vec<XmlEntry>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct XmlEntry {
+    id: String,
+    category: String,
+    query: String,
+    date: String,
+    author: String,
+}
+
+fn read_xml(content: &str) -> Result<Vec<Entry>, String> {
+    let file: XmlFile = quick_xml::de::from_str(content).map_err(|err| err.to_string())?;
+    Ok(file
+        .entries
+        .into_iter()
+        .map(|entry| Entry {
+            id: entry.id.trim().to_string(),
+            dork: plain_text(&entry.query),
+            category: plain_text(&entry.category),
+            date: entry.date.trim().to_string(),
+            author: plain_text(&entry.author),
+        })
+        .collect())
+}
+
+fn read_json(content: &str) -> Result<Vec<Entry>, String> {
+    let value: Value = serde_json::from_str(content).map_err(|err| err.to_string())?;
+    let records = match &value {
+        Value::Array(records) => records,
+        Value::Object(object) => match object.get("data") {
+            Some(Value::Array(records)) => records,
+            _ => return Err("liste `data` introuvable".to_string()),
+        },
+        _ => return Err("liste d'entrées attendue".to_string()),
+    };
+    Ok(records
+        .iter()
+        .map(|record| {
+            let field = |names: &[&str]| {
+                names
+                    .iter()
+                    .find_map(|name| record.get(*name).and_then(json_text))
+                    .unwrap_or_default()
+            };
+            Entry {
+                id: field(&["id"]),
+                dork: field(&["dork", "query", "url_title", "title"]),
+                category: field(&["category", "cat_id"]),
+                date: field(&["date", "date_published"]),
+                author: field(&["author", "author_id"]),
+            }
+        })
+        .collect())
+}
+
+/// The text of a JSON value. Exploit-DB nests the category and the author in objects, or gives
+/// them as `[id, name]` pairs.
+fn json_text(value: &Value) -> Option<String> {
+    let text = match value {
+        Value::String(text) => plain_text(text),
+        Value::Number(number) => number.to_string(),
+        Value::Object(object) => ["cat_title", "name", "title"]
+            .iter()
+            .find_map(|key| object.get(*key).and_then(json_text))?,
+        Value::Array(values) => values.last().and_then(json_text)?,
+        _ => return None,
+    };
+    Some(text).filter(|text| !text.is_empty())
+}
+
+fn read_csv(content: &str) -> Result<Vec<Entry>, String> {
+    let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(content.as_bytes());
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(|err| err.to_string())?
+        .iter()
+        .map(|header| header.trim().to_ascii_lowercase())
+        .collect();
+    let column = |names: &[&str]| names.iter().find_map(|name| headers.iter().position(|header| header == name));
+    let Some(dork) = column(&["dork", "query", "url_title", "title"]) else {
+        return Err("colonne dork, query ou title introuvable".to_string());
+    };
+    let id = column(&["id", "ghdb_id"]);
+    let category = column(&["category", "cat_title"]);
+    let date = column(&["date", "date_published"]);
+    let author = column(&["author", "author_name"]);
+
+    let mut entries = vec![];
+    for record in reader.records() {
+        let record = record.map_err(|err| err.to_string())?;
+        let get = |column: Option<usize>| {
+            column
+                .and_then(|column| record.get(column))
+                .map(plain_text)
+                .unwrap_or_default()
+        };
+        entries.push(Entry {
+            id: get(id),
+            dork: get(Some(dork)),
+            category: get(category),
+            date: get(date),
+            author: get(author),
+        });
+    }
+    Ok(entries)
+}
+
+/// Drops the HTML Exploit-DB wraps some fields in (links, entities) and trims the text.
+fn plain_text(text: &str) -> String {
+    if !text.contains('<') && !text.contains('&') {
+        return text.trim().to_string();
+    }
+    let fragment = Html::parse_fragment(text);
+    let text: String = fragment.root_element().text().collect();
+    text.trim().to_string()
+}
@@ -0,0 +1,270 @@
+use crate::date_range::DateRange;
+use crate::query::{Clause, Operator, Query, Term, is_range};
+use std::fmt;
+
+/// Google ignores every word past this limit.
+pub const MAX_WORDS: usize = 32;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug)]
+pub struct Lint {
+    pub severity: Severity,
+    pub message: String,
+    pub suggestion: Option<String>,
+}
+
+impl Lint {
+    fn error(message: impl Into<String>, suggestion: Option<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            message: message.into(),
+            suggestion,
+        }
+    }
+
+    fn warning(message: impl Into<String>, suggestion: Option<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message: message.into(),
+            suggestion,
+        }
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let icon = match self.severity {
+            Severity::Error => "⛔",
+            Severity::Warning => "⚠",
+        };
+        write!(f, "{} {}", icon, self.message)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " → {}", suggestion)?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks a query for mistakes Google silently ignores or misreads. Errors come first.
+pub fn lint(query: &Query) -> Vec<Lint> {
+    let mut lints = vec![];
+    let clauses = query.clauses();
+
+    for clause in &clauses {
+        lint_clause(clause, &mut lints);
+    }
+    lint_extension_lists(query, &mut lints);
+
+    let exclusive = clauses
+        .iter()
+        .find_map(|clause| clause.operator.filter(|op| op.takes_words()));
+    if let Some(exclusive) = exclusive
+        && clauses
+            .iter()
+            .any(|clause| clause.operator.is_some_and(|op| op != exclusive))
+    {
+        lints.push(Lint::warning(
+            format!("{}: ne se combine pas avec d'autres opérateurs", exclusive),
+            Some(format!(
+                "remplacer par un opérateur {} par mot",
+                exclusive.as_str().replace("allin", "in")
+            )),
+        ));
+    }
+
+    if !clauses.is_empty() && clauses.iter().all(|clause| clause.negated) {
+        lints.push(Lint::error(
+            "la requête ne contient que des exclusions",
+            Some("ajouter au moins un terme positif".to_string()),
+        ));
+    }
+
+    let words = word_count(query);
+    if words > MAX_WORDS {
+        lints.push(Lint::warning(
+            format!("{} mots : Google ignore tout ce qui dépasse {} mots", words, MAX_WORDS),
+            Some("raccourcir la requête ou la découper".to_string()),
+        ));
+    }
+
+    if let Err(err) = DateRange::from_query(query) {
+        lints.push(Lint::error(err.to_string(), None));
+    }
+
+    lints.sort_by_key(|lint| lint.severity);
+    lints
+}
+
+fn lint_clause(clause: &Clause, lints: &mut Vec<Lint>) {
+    let text = clause.term.text();
+    let name = clause.operator.map(Operator::as_str).unwrap_or("terme");
+
+    if matches!(&clause.term, Term::Phrase(phrase) if phrase.is_empty()) {
+        lints.push(Lint::error(
+            format!("{}: phrase vide \"\"", name),
+            Some("supprimer le terme".to_string()),
+        ));
+        return;
+    }
+
+    match clause.operator {
+        Some(operator @ (Operator::FileType | Operator::Ext)) => {
+            let values: Vec<&str> = text
+                .split(|c: char| c.is_whitespace() || c == '|')
+                .filter(|value| !value.is_empty() && *value != "OR")
+                .collect();
+            if values.len() > 1 {
+                let fix = values
+                    .iter()
+                    .map(|value| format!("{}:{}", operator, value.trim_start_matches('.')))
+                    .collect::<Vec<_>>()
+                    .join(" OR ");
+                lints.push(Lint::error(
+                    format!("{}:{} : une seule extension par opérateur", operator, text),
+                    Some(fix),
+                ));
+                return;
+            } else if let Some(stripped) = text.strip_prefix('.') {
+                lints.push(Lint::warning(
+                    format!("{}:{} : l'extension ne prend pas de point", operator, text),
+                    Some(format!("{}:{}", operator, stripped)),
+                ));
+            }
+        }
+        // Anything but `min..max` would be searched as a plain word.
+        Some(Operator::Range) if !is_range(&text) => {
+            let fix = text
+                .split_once('-')
+                .map(|(low, high)| format!("{}..{}", low.trim(), high.trim()))
+                .filter(|fix| is_range(fix))
+                .unwrap_or_else(|| "min..max".to_string());
+            lints.push(Lint::error(format!("plage « {} » : min..max attendu", text), Some(fix)));
+            return;
+        }
+        Some(Operator::Site | Operator::Related | Operator::Cache) => {
+            let bare = text
+                .trim_start_matches("https://")
+                .trim_start_matches("http://")
+                .trim_end_matches('/');
+            if bare != text {
+                lints.push(Lint::warning(
+                    format!("{}:{} : le schéma et le / final sont inutiles", name, text),
+                    Some(format!("{}:{}", name, bare)),
+                ));
+            }
+        }
+        _ => {}
+    }
+
+    if let Term::Phrase(phrase) = &clause.term
+        && (phrase.contains(" OR ") || phrase.contains('|'))
+    {
+        lints.push(Lint::warning(
+            format!("{}:\"{}\" : OR entre guillemets est cherché littéralement", name, phrase),
+            Some("utiliser un groupe OU".to_string()),
+        ));
+    }
+}
+
+/// Flags `filetype:log OR txt`, where the words after the first extension are searched as plain
+/// words rather than as extensions.
+fn lint_extension_lists(query: &Query, lints: &mut Vec<Lint>) {
+    let children = match query {
+        Query::Clause(_) => return,
+        Query::And(children) => children,
+        Query::Or(children) => {
+            let operator = children.iter().find_map(|child| match child {
+                Query::Clause(clause) if !clause.negated => {
+                    clause.operator.filter(|op| matches!(op, Operator::FileType | Operator::Ext))
+                }
+                _ => None,
+            });
+            let is_extension = |child: &Query| {
+                matches!(child, Query::Clause(Clause { operator: None, negated: false, term: Term::Word(word) })
+                    if looks_like_extension(word))
+            };
+            if let Some(operator) = operator
+                && children.iter().any(is_extension)
+            {
+                let fixed: Vec<Query> = children
+                    .iter()
+                    .map(|child| match child {
+                        Query::Clause(clause) if is_extension(child) => {
+                            Clause::with_value(operator, clause.term.text().trim_start_matches('.')).into()
+                        }
+                        _ => child.clone(),
+                    })
+                    .collect();
+                lints.push(Lint::error(
+                    format!("{} : les mots sans {}: sont cherchés comme texte", query, operator),
+                    Some(Query::any(fixed).to_string()),
+                ));
+            }
+            children
+        }
+    };
+    for child in children {
+        lint_extension_lists(child, lints);
+    }
+}
+
+/// A short run of letters and digits, not only digits, such as `txt` or `.log`.
+fn looks_like_extension(word: &str) -> bool {
+    let word = word.strip_prefix('.').unwrap_or(word);
+    (1..=5).contains(&word.len())
+        && word.chars().all(|c| c.is_ascii_alphanumeric())
+        && !word.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Counts words the way Google does towards its limit: every word, including quoted ones and `OR`.
+pub fn word_count(query: &Query) -> usize {
+    fn term_words(term: &Term) -> usize {
+        match term {
+            Term::Word(_) => 1,
+            Term::Phrase(text) => text.split_whitespace().count().max(1),
+            Term::Words(words) => words.iter().map(term_words).sum(),
+        }
+    }
+    match query {
+        Query::Clause(clause) => term_words(&clause.term),
+        Query::And(children) => children.iter().map(word_count).sum(),
+        Query::Or(children) => {
+            children.iter().map(word_count).sum::<usize>() + children.len().saturating_sub(1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+
+    #[test]
+    fn flags_bare_extensions_in_a_filetype_or_list() {
+        let lints = lint(&parse("site:example.com (filetype:log OR txt)"));
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].severity, Severity::Error);
+        assert_eq!(lints[0].suggestion.as_deref(), Some("filetype:log OR filetype:txt"));
+
+        let lints = lint(&parse("ext:env OR .ini OR password"));
+        assert_eq!(lints[0].suggestion.as_deref(), Some("ext:env OR ext:ini OR password"));
+
+        assert!(lint(&parse("filetype:log OR filetype:txt")).is_empty());
+        assert!(lint(&parse("filetype:log OR 2020")).is_empty());
+    }
+
+    #[test]
+    fn flags_ranges_that_are_not_min_max() {
+        let range = |value| lint(&Clause::with_value(Operator::Range, value).into());
+        assert!(range("100..200").is_empty());
+        let lints = range("100-200");
+        assert_eq!(lints[0].severity, Severity::Error);
+        assert_eq!(lints[0].suggestion.as_deref(), Some("100..200"));
+        assert_eq!(range("abc")[0].suggestion.as_deref(), Some("min..max"));
+    }
+}
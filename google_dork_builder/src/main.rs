@@ -1,37 +1,233 @@
+mod archive;
+mod cse;
+mod date_range;
+mod dialect;
+mod engines;
+mod ghdb;
+mod lint;
+mod options;
+mod params;
+mod parser;
+mod query;
+mod results;
+mod serp;
+mod split;
 mod templates;
+mod translate;
 
 use copypasta::{ClipboardContext, ClipboardProvider};
 use eframe::egui;
-use open;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
 use std::fs;
-use crate::templates::TEMPLATES;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use crate::date_range::{DateRange, DATE_FORMAT, parse_date, without_dates};
+use crate::cse::{CseConfig, CseError};
+use crate::dialect::Dialect;
+use crate::engines::{CustomEngine, SearchEngine};
+use crate::options::{SearchOptions, TimeRange};
+use crate::params::TemplateParam;
+use crate::query::{Clause, Operator, Query};
+use crate::results::{DorkResults, ResultStatus, ResultStore, SearchResult};
 
 const HISTORY_FILE: &str = "dork_history.json";
+const SETTINGS_FILE: &str = "dork_settings.json";
+const RESULTS_FILE: &str = "dork_results.json";
+/// Templates created in the template manager.
+const USER_TEMPLATES_FILE: &str = "mes_templates.toml";
+/// Templates imported from the Google Hacking Database.
+const GHDB_TEMPLATES_FILE: &str = "ghdb.toml";
+/// Pseudo-category listing the favourite templates.
+const FAVOURITES: &str = "⭐ Favoris";
 
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Settings {
+    search_options: SearchOptions,
+    /// Extra engines shown after the built-in ones.
+    engines: Vec<CustomEngine>,
+    /// Crawl looked up in the Common Crawl index, e.g. `CC-MAIN-2025-30`. Empty for the default.
+    common_crawl_index: String,
+    cse: CseConfig,
+    /// Template directories read in addition to `templates::user_dirs`.
+    template_dirs: Vec<String>,
+    /// IDs of the templates marked as favourite.
+    favourite_templates: Vec<String>,
+    /// The values last given to template placeholders, by placeholder name.
+    param_values: BTreeMap<String, String>,
+}
+
+/// A generated query and the ID of the template it was built from, if any.
 #[derive(Serialize, Deserialize, Clone)]
+struct HistoryEntry {
+    query: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    template: Option<String>,
+}
+
+/// History files written before entries had a template are plain lists of queries.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredHistoryEntry {
+    Entry(HistoryEntry),
+    Query(String),
+}
+
+impl From<StoredHistoryEntry> for HistoryEntry {
+    fn from(stored: StoredHistoryEntry) -> Self {
+        match stored {
+            StoredHistoryEntry::Entry(entry) => entry,
+            StoredHistoryEntry::Query(query) => HistoryEntry { query, template: None },
+        }
+    }
+}
+
+/// A template as written in a template file. Operator values are keyed by operator name, e.g.
+/// `site = ["example.com"]`.
+#[derive(Default, Serialize, Deserialize, Clone)]
+#[serde(default)]
 struct DorkTemplate {
-    name: &'static str,
-    category: &'static str,
-    site: &'static str,
-    inurl: &'static str,
-    intitle: &'static str,
-    filetype: &'static str,
-    intext: &'static str,
+    /// Unique and stable across template pack updates; derived from the file and name when a
+    /// file leaves it out.
+    id: String,
+    name: String,
+    category: String,
+    #[serde(flatten)]
+    fields: BTreeMap<Operator, Vec<String>>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    keywords: String,
+    dialect: Dialect,
+    /// The same search written for other modes, e.g. a Shodan query for a camera dork.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    variants: BTreeMap<Dialect, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<SearchOptions>,
+    /// IDs of related templates.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    see_also: Vec<String>,
+    /// The entry number in the Exploit-DB Google Hacking Database, with its date and author.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ghdb_id: Option<u32>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    date: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    author: String,
+    /// Declared `{{name}}` placeholders. Those used without being declared get a type, and a
+    /// description, from their name.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    params: Vec<TemplateParam>,
+    /// The file the template was loaded from; `None` for the bundled templates, which are
+    /// read-only.
+    #[serde(skip)]
+    source: Option<std::path::PathBuf>,
 }
 
 impl DorkTemplate {
+    fn to_data(&self) -> DorkData {
+        let fields = self
+            .fields
+            .iter()
+            .map(|(operator, values)| {
+                let values = values.iter().map(|value| FieldValue::new(value)).collect();
+                (*operator, Field { values, any: false })
+            })
+            .collect();
+        DorkData {
+            fields,
+            keywords: self.keywords.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// A template holding the form's values. What a template cannot hold per operator
+    /// (exclusions, OR fields and groups) is kept, rendered, in `keywords`.
+    fn from_data(data: &DorkData, dialect: Dialect) -> Self {
+        let mut fields: BTreeMap<Operator, Vec<String>> = BTreeMap::new();
+        let mut rest = vec![];
+        for (operator, field) in &data.fields {
+            if field.any {
+                rest.push(Query::any(field.clauses(*operator).map(Query::from).collect()));
+                continue;
+            }
+            for row in field.values.iter().filter(|row| !row.value.trim().is_empty()) {
+                if row.exclude {
+                    rest.push(row_query(Some(*operator), row));
+                } else {
+                    fields.entry(*operator).or_default().push(row.value.clone());
+                }
+            }
+        }
+        rest.extend(data.groups.iter().map(Group::to_query));
+        match parser::parse(&data.keywords) {
+            Query::And(extra) => rest.extend(extra),
+            extra => rest.push(extra),
+        }
+        Self {
+            fields,
+            keywords: Query::all(rest).to_string(),
+            dialect,
+            ..Default::default()
+        }
+    }
+
+    /// Names of the placeholders used in the operator values, the keywords and the variants.
+    fn placeholders(&self) -> Vec<String> {
+        let texts = self
+            .fields
+            .values()
+            .flatten()
+            .chain([&self.keywords])
+            .chain(self.variants.values());
+        let mut names: Vec<String> = vec![];
+        for name in texts.flat_map(|text| params::placeholders(text)) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    /// The parameters asked for when the template is applied, one per placeholder.
+    fn params(&self) -> Vec<TemplateParam> {
+        self.placeholders()
+            .iter()
+            .map(|name| {
+                self.params
+                    .iter()
+                    .find(|param| param.name == *name)
+                    .cloned()
+                    .unwrap_or_else(|| TemplateParam::implicit(name))
+            })
+            .collect()
+    }
+
+    /// A copy with the placeholders that have a value replaced by it.
+    fn instantiate(&self, values: &BTreeMap<String, String>) -> DorkTemplate {
+        let mut tpl = self.clone();
+        for value in tpl.fields.values_mut().flatten() {
+            *value = params::substitute(value, values);
+        }
+        tpl.keywords = params::substitute(&tpl.keywords, values);
+        for variant in tpl.variants.values_mut() {
+            *variant = params::substitute(variant, values);
+        }
+        tpl
+    }
+
+    fn dialects(&self) -> Vec<Dialect> {
+        let mut dialects = vec![self.dialect];
+        dialects.extend(self.variants.keys().copied());
+        dialects
+    }
+
     fn matches_category(&self, category: &str) -> bool {
         self.category.eq_ignore_ascii_case(category)
     }
 }
 
-fn filter_dorks_by_category(dorks: &[DorkTemplate], category: &str) -> Vec<DorkTemplate> {
-    dorks
-        .iter()
-        .filter(|d| d.matches_category(category))
-        .cloned()
-        .collect()
+fn filter_dorks_by_category<'a>(dorks: &'a [DorkTemplate], category: &str) -> Vec<&'a DorkTemplate> {
+    dorks.iter().filter(|d| d.matches_category(category)).collect()
 }
 
 fn unique_categories(dorks: &[DorkTemplate]) -> Vec<String> {
@@ -41,105 +237,941 @@ fn unique_categories(dorks: &[DorkTemplate]) -> Vec<String> {
     categories
 }
 
+#[derive(Default, Serialize, Deserialize, Clone)]
+struct FieldValue {
+    value: String,
+    exclude: bool,
+}
+
+impl FieldValue {
+    fn new(value: &str) -> Self {
+        Self {
+            value: value.to_string(),
+            exclude: false,
+        }
+    }
+
+    fn from_clause(clause: &Clause) -> Self {
+        Self {
+            value: clause.term.text(),
+            exclude: clause.negated,
+        }
+    }
+}
+
+#[derive(Default, Serialize, Deserialize, Clone)]
+struct Field {
+    values: Vec<FieldValue>,
+    any: bool,
+}
+
+impl Field {
+    fn clauses(&self, operator: Operator) -> impl Iterator<Item = Clause> + '_ {
+        self.values
+            .iter()
+            .filter(|row| !row.value.trim().is_empty())
+            .map(move |row| {
+                let clause = Clause::with_value(operator, &row.value);
+                if row.exclude { clause.negate() } else { clause }
+            })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+enum GroupItem {
+    Clause(Option<Operator>, FieldValue),
+    Group(Group),
+}
+
+/// A parenthesised sub-expression whose items are joined with AND, or with OR when `any` is set.
+#[derive(Default, Serialize, Deserialize, Clone)]
+struct Group {
+    items: Vec<GroupItem>,
+    any: bool,
+}
+
+impl Group {
+    fn to_query(&self) -> Query {
+        let children = self
+            .items
+            .iter()
+            .map(|item| match item {
+                GroupItem::Clause(operator, row) => row_query(*operator, row),
+                GroupItem::Group(group) => group.to_query(),
+            })
+            .collect();
+        if self.any {
+            Query::any(children)
+        } else {
+            Query::all(children)
+        }
+    }
+
+    fn from_query(query: &Query) -> Self {
+        let (children, any) = match query {
+            Query::And(children) => (children.as_slice(), false),
+            Query::Or(children) => (children.as_slice(), true),
+            clause => (std::slice::from_ref(clause), false),
+        };
+        let items = children
+            .iter()
+            .map(|child| match child {
+                Query::Clause(clause) if clause.operator.is_some() => {
+                    GroupItem::Clause(clause.operator, FieldValue::from_clause(clause))
+                }
+                Query::Clause(clause) => GroupItem::Clause(
+                    None,
+                    FieldValue {
+                        value: clause.term.to_string(),
+                        exclude: clause.negated,
+                    },
+                ),
+                _ => GroupItem::Group(Group::from_query(child)),
+            })
+            .collect();
+        Self { items, any }
+    }
+}
+
+/// Builds the query for one row. Rows without an operator are free text and go through the parser.
+fn row_query(operator: Option<Operator>, row: &FieldValue) -> Query {
+    if row.value.trim().is_empty() {
+        return Query::default();
+    }
+    let query = match operator {
+        Some(operator) => Clause::with_value(operator, &row.value).into(),
+        None => parser::parse(&row.value),
+    };
+    match query {
+        Query::Clause(clause) if row.exclude => clause.negate().into(),
+        query => query,
+    }
+}
+
 #[derive(Default, Serialize, Deserialize, Clone)]
 struct DorkData {
-    site: String,
-    inurl: String,
-    intitle: String,
-    filetype: String,
-    intext: String,
-    operator: String,
+    fields: BTreeMap<Operator, Field>,
+    groups: Vec<Group>,
+    keywords: String,
+}
+
+impl DorkData {
+    fn to_query(&self) -> Query {
+        let mut children = vec![];
+
+        for (operator, field) in &self.fields {
+            let clauses = field.clauses(*operator).map(Query::from).collect();
+            if field.any {
+                children.push(Query::any(clauses));
+            } else {
+                children.extend(clauses);
+            }
+        }
+
+        children.extend(self.groups.iter().map(Group::to_query));
+
+        match parser::parse(&self.keywords) {
+            Query::And(extra) => children.extend(extra),
+            extra => children.push(extra),
+        }
+
+        Query::all(children)
+    }
+
+    /// Fills the form from a parsed query. Clauses go to their operator field when possible,
+    /// compound sub-expressions become groups and anything else is kept, rendered, in `keywords`.
+    fn from_query(query: &Query) -> Self {
+        let mut data = DorkData::default();
+        if data.take_alternatives(query) {
+            return data;
+        }
+
+        let children = match query {
+            Query::And(children) => children.as_slice(),
+            Query::Or(_) => {
+                data.groups.push(Group::from_query(query));
+                return data;
+            }
+            clause => std::slice::from_ref(clause),
+        };
+
+        let mut rest = vec![];
+        for child in children {
+            match child {
+                Query::Clause(clause) => {
+                    if !data.take_clause(clause) {
+                        rest.push(child.clone());
+                    }
+                }
+                _ => {
+                    if !data.take_alternatives(child) {
+                        data.groups.push(Group::from_query(child));
+                    }
+                }
+            }
+        }
+
+        data.keywords = Query::all(rest).to_string();
+        data
+    }
+
+    fn take_clause(&mut self, clause: &Clause) -> bool {
+        let Some(operator) = clause.operator else {
+            return false;
+        };
+        let field = self.fields.entry(operator).or_default();
+        if field.any || clause.term.text().is_empty() {
+            return false;
+        }
+        field.values.push(FieldValue::from_clause(clause));
+        true
+    }
+
+    /// Takes an OR of clauses sharing one operator, e.g. `filetype:env OR filetype:ini`.
+    fn take_alternatives(&mut self, query: &Query) -> bool {
+        let Query::Or(children) = query else {
+            return false;
+        };
+        let mut operator = None;
+        let mut values = vec![];
+        for child in children {
+            match child {
+                Query::Clause(clause)
+                    if clause.operator.is_some()
+                        && operator.is_none_or(|op| clause.operator == Some(op))
+                        && !clause.term.text().is_empty() =>
+                {
+                    operator = clause.operator;
+                    values.push(FieldValue::from_clause(clause));
+                }
+                _ => return false,
+            }
+        }
+
+        let Some(operator) = operator else {
+            return false;
+        };
+        let field = self.fields.entry(operator).or_default();
+        if !field.values.is_empty() {
+            return false;
+        }
+        *field = Field { values, any: true };
+        true
+    }
+}
+
+fn field_ui(ui: &mut egui::Ui, operator: Operator, field: &mut Field) {
+    if field.values.is_empty() {
+        field.values.push(FieldValue::default());
+    }
+
+    ui.horizontal(|ui| {
+        if operator.has_prefix() {
+            ui.label(format!("{}:", operator));
+        } else {
+            ui.label("plage numérique (min..max):");
+        }
+        if ui.small_button("➕").clicked() {
+            field.values.push(FieldValue::default());
+        }
+        if field.values.len() > 1 {
+            ui.checkbox(&mut field.any, "OU");
+        }
+    });
+
+    let mut removed = None;
+    for (i, row) in field.values.iter_mut().enumerate() {
+        ui.push_id((operator, i), |ui| {
+            ui.horizontal(|ui| {
+                ui.toggle_value(&mut row.exclude, "🚫")
+                    .on_hover_text("Exclure (-opérateur:valeur)");
+                ui.text_edit_singleline(&mut row.value);
+                if matches!(operator, Operator::Before | Operator::After) {
+                    let mut date =
+                        parse_date(&row.value).unwrap_or_else(|| chrono::Local::now().date_naive());
+                    if ui.add(egui_extras::DatePickerButton::new(&mut date)).changed() {
+                        row.value = date.format(DATE_FORMAT).to_string();
+                    }
+                }
+                if ui.small_button("➖").clicked() {
+                    removed = Some(i);
+                }
+            });
+        });
+    }
+    if let Some(i) = removed {
+        field.values.remove(i);
+    }
+}
+
+/// Draws a group and its nested groups. Returns true when the user asked to remove it.
+fn group_ui(ui: &mut egui::Ui, group: &mut Group, operators: &[Operator]) -> bool {
+    let mut remove_group = false;
+    ui.group(|ui| {
+        ui.horizontal(|ui| {
+            ui.label("(");
+            ui.selectable_value(&mut group.any, false, "ET");
+            ui.selectable_value(&mut group.any, true, "OU");
+            if ui.small_button("➕ Terme").clicked() {
+                group.items.push(GroupItem::Clause(None, FieldValue::default()));
+            }
+            if ui.small_button("➕ Sous-groupe").clicked() {
+                group.items.push(GroupItem::Group(Group::default()));
+            }
+            if ui.small_button("🗑").clicked() {
+                remove_group = true;
+            }
+        });
+
+        let mut removed = None;
+        for (i, item) in group.items.iter_mut().enumerate() {
+            ui.push_id(i, |ui| match item {
+                GroupItem::Clause(operator, row) => {
+                    ui.horizontal(|ui| {
+                        egui::ComboBox::from_id_salt("group_operator")
+                            .selected_text(operator.map(Operator::as_str).unwrap_or("texte"))
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(operator, None, "texte");
+                                for op in operators {
+                                    ui.selectable_value(operator, Some(*op), op.as_str());
+                                }
+                            });
+                        ui.toggle_value(&mut row.exclude, "🚫")
+                            .on_hover_text("Exclure (-opérateur:valeur)");
+                        ui.text_edit_singleline(&mut row.value);
+                        if ui.small_button("➖").clicked() {
+                            removed = Some(i);
+                        }
+                    });
+                }
+                GroupItem::Group(inner) => {
+                    if group_ui(ui, inner, operators) {
+                        removed = Some(i);
+                    }
+                }
+            });
+        }
+        if let Some(i) = removed {
+            group.items.remove(i);
+        }
+        ui.label(")");
+    });
+    remove_group
+}
+
+/// Key under which two spellings of the same query compare equal.
+fn canonical_key(query: &str) -> String {
+    parser::parse(query).canonical().to_string()
+}
+
+fn options_ui(ui: &mut egui::Ui, options: &mut SearchOptions) {
+    egui::Grid::new("search_options").num_columns(2).show(ui, |ui| {
+        ui.label("Langue de l'interface (hl) :");
+        ui.text_edit_singleline(&mut options.language);
+        ui.end_row();
+
+        ui.label("Région (gl) :");
+        ui.text_edit_singleline(&mut options.region);
+        ui.end_row();
+
+        ui.label("Langue des résultats (lr) :");
+        ui.text_edit_singleline(&mut options.results_language);
+        ui.end_row();
+
+        ui.label("Nombre de résultats (0 = défaut) :");
+        ui.add(egui::DragValue::new(&mut options.num).range(0..=100));
+        ui.end_row();
+
+        ui.label("Période :");
+        egui::ComboBox::from_id_salt("time_range")
+            .selected_text(options.time_range.label())
+            .show_ui(ui, |ui| {
+                for range in TimeRange::ALL {
+                    ui.selectable_value(&mut options.time_range, *range, range.label());
+                }
+            });
+        ui.end_row();
+    });
+    ui.checkbox(&mut options.verbatim, "Verbatim (mots exacts)");
+    ui.checkbox(&mut options.no_filter, "Afficher les résultats similaires (filter=0)");
+    ui.checkbox(&mut options.safe_off, "SafeSearch désactivé");
+}
+
+/// Text fields for placeholder values; an empty field stands for the parameter's default.
+fn params_ui(ui: &mut egui::Ui, params: &[TemplateParam], values: &mut BTreeMap<String, String>) {
+    egui::Grid::new("template_params").num_columns(3).show(ui, |ui| {
+        for param in params {
+            ui.label(format!("{{{{{}}}}} :", param.name));
+            let value = values.entry(param.name.clone()).or_default();
+            ui.add(egui::TextEdit::singleline(value).hint_text(&param.default));
+            ui.label(egui::RichText::new(param.description()).weak());
+            ui.end_row();
+        }
+    });
+}
+
+fn cse_config_ui(ui: &mut egui::Ui, config: &mut CseConfig) {
+    egui::Grid::new("cse_config").num_columns(2).show(ui, |ui| {
+        ui.label("Clé d'API :");
+        ui.add(egui::TextEdit::singleline(&mut config.api_key).password(true));
+        ui.end_row();
+
+        ui.label("Identifiant du moteur (cx) :");
+        ui.text_edit_singleline(&mut config.cx);
+        ui.end_row();
+
+        ui.label("Point d'accès :");
+        ui.add(egui::TextEdit::singleline(&mut config.endpoint).hint_text(cse::ENDPOINT));
+        ui.end_row();
+    });
+}
+
+/// Lists the stored results of a dork. Returns true when a status was changed.
+fn results_ui(ui: &mut egui::Ui, dork: &mut DorkResults, filter: &mut Option<ResultStatus>) -> bool {
+    let mut changed = false;
+    let new = dork.results.iter().filter(|result| dork.is_new(result)).count();
+    let title = format!(
+        "📄 Résultats ({}, {} nouveaux au passage du {})",
+        dork.results.len(),
+        new,
+        dork.last_run
+    );
+    egui::CollapsingHeader::new(title).default_open(true).show(ui, |ui| {
+        ui.horizontal(|ui| {
+            ui.label("Afficher :");
+            ui.selectable_value(filter, None, "Tous");
+            for status in ResultStatus::ALL {
+                ui.selectable_value(filter, Some(*status), status.label());
+            }
+        });
+        let last_run = dork.last_run.clone();
+        for (i, result) in dork.results.iter_mut().enumerate() {
+            if filter.is_some_and(|status| status != result.status) {
+                continue;
+            }
+            ui.push_id(("result", i), |ui| {
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_salt("status")
+                        .selected_text(result.status.label())
+                        .show_ui(ui, |ui| {
+                            for status in ResultStatus::ALL {
+                                changed |= ui
+                                    .selectable_value(&mut result.status, *status, status.label())
+                                    .changed();
+                            }
+                        });
+                    if result.first_seen == last_run {
+                        ui.colored_label(egui::Color32::LIGHT_GREEN, "🆕");
+                    }
+                    ui.hyperlink_to(&result.title, &result.link);
+                });
+                ui.label(egui::RichText::new(&result.link).small().weak());
+                ui.label(&result.snippet);
+            });
+        }
+    });
+    changed
+}
+
+fn copy_to_clipboard(text: &str) {
+    let mut ctx = ClipboardContext::new().unwrap();
+    let _ = ctx.set_contents(text.to_string());
+}
+
+/// A change asked for in the template manager.
+enum TemplateAction {
+    Create,
+    Update(String),
+    Duplicate(String),
+    Delete(String),
+}
+
+/// Sent by the thread running a Custom Search query.
+enum CseMessage {
+    Page(Vec<SearchResult>),
+    Done(Option<CseError>),
 }
 
 struct DorkApp {
     data: DorkData,
     query: String,
-    history: Vec<String>,
-    selected_template: usize,
+    history: Vec<HistoryEntry>,
+    selected_template: Option<String>,
+    /// ID of the template the form was last filled from.
+    source_template: Option<String>,
     selected_history: usize,
-    available_operators: Vec<&'static str>,
     pub selected_category: String,
+    dates_in_url: bool,
+    over_limit: bool,
+    split_queries: Vec<String>,
+    matching_template: Option<String>,
+    templates: Vec<DorkTemplate>,
+    template_errors: Vec<String>,
+    dialect: Dialect,
+    engines: Vec<Box<dyn SearchEngine>>,
+    selected_engine: usize,
+    settings: Settings,
+    search_options: SearchOptions,
+    cse_max_results: u32,
+    /// The query being run and the results received so far, recorded once the run is over.
+    cse_query: String,
+    cse_results: Vec<SearchResult>,
+    cse_error: Option<String>,
+    cse_receiver: Option<Receiver<CseMessage>>,
+    results: ResultStore,
+    result_filter: Option<ResultStatus>,
+    serp_path: String,
+    serp_status: Option<Result<String, String>>,
+    /// Name and category typed in the template manager.
+    template_name: String,
+    template_category: String,
+    template_status: Option<Result<String, String>>,
+    ghdb_path: String,
+    ghdb_status: Option<Result<String, String>>,
+    ghdb_failures: Vec<String>,
+    /// The template waiting for its placeholder values before it is applied.
+    param_prompt: Option<String>,
+    param_errors: Vec<String>,
+    /// Name and query of each template instantiated in bulk.
+    bulk_queries: Vec<(String, String)>,
+    bulk_errors: Vec<String>,
 }
 
 impl DorkApp {
     fn generate_query(&mut self) {
-        let mut parts = vec![];
+        let model = self.data.to_query();
+        self.query = model.to_string();
+        self.over_limit = !split::fits(&model, self.engine());
+        self.split_queries.clear();
 
-        let op = self.data.operator.trim();
-        let glue = if op.is_empty() {
-            " "
-        } else {
-            &*format!(" {} ", op)
-        };
+        let key = model.canonical().to_string();
+        self.matching_template = self
+            .templates
+            .iter()
+            .find(|tpl| {
+                let Ok(values) = params::bind(&tpl.params(), &self.settings.param_values) else {
+                    return false;
+                };
+                tpl.instantiate(&values).to_data().to_query().canonical().to_string() == key
+            })
+            .map(|tpl| tpl.name.clone());
 
-        if !self.data.site.is_empty() {
-            parts.push(format!("site:{}", self.data.site));
+        if !self.query.is_empty() && !self.history.iter().any(|entry| canonical_key(&entry.query) == key) {
+            self.history.push(HistoryEntry {
+                query: self.query.clone(),
+                template: self.source_template.clone(),
+            });
+            let _ = self.save_history();
         }
-        if !self.data.inurl.is_empty() {
-            parts.push(format!("inurl:\"{}\"", self.data.inurl));
+    }
+
+    fn save_history(&self) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(&self.history)?;
+        fs::write(HISTORY_FILE, json)
+    }
+
+    fn save_settings(&self) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(&self.settings)?;
+        fs::write(SETTINGS_FILE, json)
+    }
+
+    fn load_settings(&mut self) {
+        if let Ok(content) = fs::read_to_string(SETTINGS_FILE)
+            && let Ok(parsed) = serde_json::from_str::<Settings>(&content)
+        {
+            self.settings = parsed;
         }
-        if !self.data.intitle.is_empty() {
-            parts.push(format!("intitle:\"{}\"", self.data.intitle));
+        self.search_options = self.settings.search_options.clone();
+        self.engines = engines::all_engines(&self.settings.engines);
+        self.selected_engine = self.selected_engine.min(self.engines.len() - 1);
+        self.set_dialect(self.dialect);
+    }
+
+    fn load_templates(&mut self) {
+        let mut dirs = templates::user_dirs();
+        dirs.extend(self.settings.template_dirs.iter().map(std::path::PathBuf::from));
+        (self.templates, self.template_errors) = templates::load(&dirs);
+    }
+
+    fn load_history(&mut self) {
+        if let Ok(content) = fs::read_to_string(HISTORY_FILE)
+            && let Ok(parsed) = serde_json::from_str::<Vec<StoredHistoryEntry>>(&content)
+        {
+            let mut seen = HashSet::new();
+            self.history = parsed
+                .into_iter()
+                .map(HistoryEntry::from)
+                .filter(|entry| seen.insert(canonical_key(&entry.query)))
+                .collect();
         }
-        if !self.data.filetype.is_empty() {
-            parts.push(format!("filetype:{}", self.data.filetype));
+    }
+
+    fn template(&self, id: &str) -> Option<&DorkTemplate> {
+        self.templates.iter().find(|tpl| tpl.id == id)
+    }
+
+    /// Fills the form from a template, once its placeholder values are given when it has any.
+    fn apply_template(&mut self, id: &str) {
+        let Some(tpl) = self.template(id).cloned() else {
+            return;
+        };
+        self.selected_template = Some(tpl.id.clone());
+        self.template_name = tpl.name.clone();
+        self.template_category = tpl.category.clone();
+        if tpl.params().is_empty() {
+            self.fill_from_template(tpl);
+        } else {
+            self.param_prompt = Some(tpl.id);
+            self.param_errors.clear();
         }
-        if !self.data.intext.is_empty() {
-            parts.push(format!("intext:\"{}\"", self.data.intext));
+    }
+
+    /// Applies the template waiting in the placeholder prompt with the values typed in.
+    fn confirm_params(&mut self) {
+        let Some(tpl) = self.param_prompt.as_deref().and_then(|id| self.template(id)).cloned() else {
+            self.param_prompt = None;
+            return;
+        };
+        match params::bind(&tpl.params(), &self.settings.param_values) {
+            Ok(values) => {
+                self.param_prompt = None;
+                let _ = self.save_settings();
+                self.fill_from_template(tpl.instantiate(&values));
+                self.generate_query();
+            }
+            Err(errors) => self.param_errors = errors,
         }
+    }
 
-        self.query = parts.join(&glue);
+    /// Renders every template of `ids` with the current placeholder values, in the current mode
+    /// when the template has a variant for it.
+    fn instantiate_all(&mut self, ids: &[String]) {
+        let mut queries = vec![];
+        let mut errors: Vec<String> = vec![];
+        for tpl in ids.iter().filter_map(|id| self.template(id)) {
+            match params::bind(&tpl.params(), &self.settings.param_values) {
+                Ok(values) => {
+                    let tpl = tpl.instantiate(&values);
+                    let query = match tpl.variants.get(&self.dialect) {
+                        Some(variant) => variant.clone(),
+                        None => tpl.to_data().to_query().to_string(),
+                    };
+                    queries.push((tpl.name, query));
+                }
+                Err(missing) => {
+                    for error in missing {
+                        if !errors.contains(&error) {
+                            errors.push(error);
+                        }
+                    }
+                }
+            }
+        }
+        self.bulk_queries = queries;
+        self.bulk_errors = errors;
+        let _ = self.save_settings();
+    }
 
-        if !self.query.is_empty() && !self.history.contains(&self.query) {
-            self.history.push(self.query.clone());
-            let _ = self.save_history();
+    /// Asks for the placeholder values of the template waiting to be applied.
+    fn params_window(&mut self, ctx: &egui::Context) {
+        let Some(tpl) = self.param_prompt.as_deref().and_then(|id| self.template(id)) else {
+            return;
+        };
+        let (name, params) = (tpl.name.clone(), tpl.params());
+        let (mut confirmed, mut cancelled) = (false, false);
+        egui::Window::new("🧩 Paramètres du template")
+            .collapsible(false)
+            .show(ctx, |ui| {
+                ui.label(&name);
+                params_ui(ui, &params, &mut self.settings.param_values);
+                for error in &self.param_errors {
+                    ui.colored_label(egui::Color32::RED, format!("⛔ {}", error));
+                }
+                ui.horizontal(|ui| {
+                    confirmed = ui.button("✔ Appliquer").clicked();
+                    cancelled = ui.button("Annuler").clicked();
+                });
+            });
+        if confirmed {
+            self.confirm_params();
+        } else if cancelled {
+            self.param_prompt = None;
         }
     }
 
-    fn save_history(&self) -> std::io::Result<()> {
-        let json = serde_json::to_string_pretty(&self.history)?;
-        fs::write(HISTORY_FILE, json)
+    fn fill_from_template(&mut self, tpl: DorkTemplate) {
+        self.source_template = Some(tpl.id.clone());
+        // A template without options goes back to the defaults rather than keep the last ones.
+        self.search_options = tpl.options.clone().unwrap_or_else(|| self.settings.search_options.clone());
+        if let Some(variant) = tpl.variants.get(&self.dialect) {
+            self.data = DorkData::from_query(&parser::parse(variant));
+            return;
+        }
+        self.data = tpl.to_data();
+        self.set_dialect(tpl.dialect);
     }
 
-    fn load_history(&mut self) {
-        if let Ok(content) = fs::read_to_string(HISTORY_FILE) {
-            if let Ok(parsed) = serde_json::from_str::<Vec<String>>(&content) {
-                self.history = parsed;
+    /// The search options to store with a template: the current ones, unless they are the
+    /// defaults.
+    fn template_options(&self) -> Option<SearchOptions> {
+        (self.search_options != self.settings.search_options).then(|| self.search_options.clone())
+    }
+
+    /// Carries out a template manager action on the user template files, then reloads them.
+    fn edit_templates(&mut self, action: TemplateAction) {
+        let name = self.template_name.trim().to_string();
+        let category = self.template_category.trim().to_string();
+        let result = match action {
+            TemplateAction::Create => {
+                if name.is_empty() {
+                    self.template_status = Some(Err("donnez un nom au template".to_string()));
+                    return;
+                }
+                let template = DorkTemplate {
+                    id: self.unique_template_id(&name),
+                    name,
+                    category,
+                    options: self.template_options(),
+                    ..DorkTemplate::from_data(&self.data, self.dialect)
+                };
+                self.write_template(&templates::user_file(USER_TEMPLATES_FILE), template, "créé")
+            }
+            TemplateAction::Update(id) => {
+                let Some(mut template) = self.template(&id).cloned() else {
+                    return;
+                };
+                let Some(path) = template.source.clone() else {
+                    return;
+                };
+                // A variant being shown is what the form holds, not the template's own fields.
+                if self.dialect != template.dialect && template.variants.contains_key(&self.dialect) {
+                    template.variants.insert(self.dialect, self.data.to_query().to_string());
+                } else {
+                    let mut data = DorkTemplate::from_data(&self.data, self.dialect);
+                    // Values given for the template's placeholders go back to being placeholders.
+                    if let Ok(values) = params::bind(&template.params(), &self.settings.param_values) {
+                        for value in data.fields.values_mut().flatten() {
+                            if let Some((name, _)) = values.iter().find(|(_, bound)| *bound == value) {
+                                *value = format!("{{{{{}}}}}", name);
+                            }
+                        }
+                    }
+                    template.fields = data.fields;
+                    template.keywords = data.keywords;
+                    template.dialect = data.dialect;
+                }
+                if !name.is_empty() {
+                    template.name = name;
+                }
+                template.category = category;
+                template.options = self.template_options();
+                self.write_template(&path, template, "enregistré")
+            }
+            TemplateAction::Duplicate(id) => {
+                let Some(template) = self.template(&id).cloned() else {
+                    return;
+                };
+                let name = format!("{} (copie)", template.name);
+                let template = DorkTemplate {
+                    id: self.unique_template_id(&name),
+                    name,
+                    ..template
+                };
+                self.write_template(&templates::user_file(USER_TEMPLATES_FILE), template, "dupliqué")
+            }
+            TemplateAction::Delete(id) => {
+                let Some(template) = self.template(&id).cloned() else {
+                    return;
+                };
+                let Some(path) = template.source else {
+                    return;
+                };
+                templates::remove(&path, &id).map(|()| {
+                    self.selected_template = None;
+                    format!("Template « {} » supprimé de {}", template.name, path.display())
+                })
             }
+        };
+        self.template_status = Some(result);
+        self.load_templates();
+    }
+
+    /// Imports a GHDB export into the GHDB template file, replacing the entries already imported.
+    fn import_ghdb(&mut self) {
+        let path = self.ghdb_path.trim().trim_matches('"').to_string();
+        self.ghdb_failures.clear();
+        let import = match ghdb::import_file(std::path::Path::new(&path)) {
+            Ok(import) => import,
+            Err(err) => {
+                self.ghdb_status = Some(Err(err));
+                return;
+            }
+        };
+        let target = templates::user_file(GHDB_TEMPLATES_FILE);
+        self.ghdb_status = Some(templates::save_all(&target, &import.templates).map(|()| {
+            format!(
+                "{} dorks importés dans {}, {} ignorés",
+                import.templates.len(),
+                target.display(),
+                import.failures.len()
+            )
+        }));
+        self.ghdb_failures = import.failures.iter().map(ToString::to_string).collect();
+        self.load_templates();
+    }
+
+    fn write_template(
+        &mut self,
+        path: &std::path::Path,
+        template: DorkTemplate,
+        done: &str,
+    ) -> Result<String, String> {
+        templates::save(path, &template)?;
+        let message = format!("Template « {} » {} dans {}", template.name, done, path.display());
+        self.selected_template = Some(template.id.clone());
+        self.source_template = Some(template.id);
+        Ok(message)
+    }
+
+    /// An ID for a new template, from its name, not used by any loaded template.
+    fn unique_template_id(&self, name: &str) -> String {
+        let base = format!("user/{}", templates::slug(name));
+        let mut id = base.clone();
+        let mut n = 2;
+        while self.template(&id).is_some() {
+            id = format!("{}-{}", base, n);
+            n += 1;
         }
+        id
     }
 
-    fn apply_template(&mut self, index: usize) {
-        let tpl = &TEMPLATES[index];
-        self.data.inurl = tpl.inurl.to_string();
-        self.data.intitle = tpl.intitle.to_string();
-        self.data.filetype = tpl.filetype.to_string();
-        self.data.site = tpl.site.to_string();
-        self.data.intext = tpl.intext.to_string();
+    /// Switches the form and engine list to `dialect`, keeping the engine when it speaks it.
+    fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect = dialect;
+        if self.engine().dialect() != dialect
+            && let Some(index) = self.engines.iter().position(|engine| engine.dialect() == dialect)
+        {
+            self.selected_engine = index;
+        }
     }
 
-    fn apply_query_string(&mut self, query: &str) {
-        self.data.inurl.clear();
-        self.data.intitle.clear();
-        self.data.filetype.clear();
-        self.data.site.clear();
-
-        for token in query.split_whitespace() {
-            if let Some(rest) = token.strip_prefix("inurl:") {
-                self.data.inurl = rest.to_string();
-            } else if let Some(rest) = token.strip_prefix("intitle:") {
-                self.data.intitle = rest.to_string();
-            } else if let Some(rest) = token.strip_prefix("filetype:") {
-                self.data.filetype = rest.to_string();
-            } else if let Some(rest) = token.strip_prefix("site:") {
-                self.data.site = rest.to_string();
-            } else {
-                if !self.data.intext.is_empty() {
-                    self.data.intext.push(' ');
+    fn engine(&self) -> &dyn SearchEngine {
+        self.engines[self.selected_engine].as_ref()
+    }
+
+    fn search_url(&self, query: &str) -> String {
+        let engine = self.engine();
+        let mut parsed = parser::parse(query);
+        let mut range = None;
+        if self.dates_in_url
+            && engine.handles_date_range()
+            && let Ok(dates) = DateRange::from_query(&parsed)
+            && !dates.is_empty()
+        {
+            parsed = without_dates(&parsed);
+            range = Some(dates);
+        }
+        let params = engine.url_params(&self.search_options, range.as_ref());
+        engine.search_url(&translate::translate(&parsed, engine).query, &params)
+    }
+
+    /// Runs the query through the Custom Search API on a background thread.
+    fn run_cse(&mut self, ctx: &egui::Context) {
+        let query = translate::translate(&parser::parse(&self.query), &engines::Google).query;
+        let config = self.settings.cse.clone();
+        let options = self.search_options.clone();
+        let max_results = self.cse_max_results;
+        let ctx = ctx.clone();
+        let (sender, receiver) = mpsc::channel();
+        self.cse_query = self.query.clone();
+        self.cse_results.clear();
+        self.cse_error = None;
+        self.cse_receiver = Some(receiver);
+
+        thread::spawn(move || {
+            let result = cse::search(&config, &query, &options, max_results, &mut |page| {
+                let _ = sender.send(CseMessage::Page(page));
+                ctx.request_repaint();
+            });
+            let _ = sender.send(CseMessage::Done(result.err()));
+            ctx.request_repaint();
+        });
+    }
+
+    fn poll_cse(&mut self) {
+        let Some(receiver) = &self.cse_receiver else {
+            return;
+        };
+        let mut done = false;
+        while let Ok(message) = receiver.try_recv() {
+            match message {
+                CseMessage::Page(results) => self.cse_results.extend(results),
+                CseMessage::Done(error) => {
+                    self.cse_error = error.map(|err| err.to_string());
+                    done = true;
                 }
-                self.data.intext.push_str(token);
             }
         }
+        if done {
+            self.cse_receiver = None;
+            let results = std::mem::take(&mut self.cse_results);
+            self.record_results(&self.cse_query.clone(), results);
+        }
+    }
+
+    fn record_results(&mut self, query: &str, results: Vec<SearchResult>) {
+        self.results.record(canonical_key(query), query, results);
+        let _ = self.results.save(RESULTS_FILE);
+    }
+
+    /// Imports a saved result page and files its results under the dork that produced it: the
+    /// history entry whose query, or translation for one of the engines, matches the page's `q=`.
+    fn import_serp(&mut self) {
+        let path = self.serp_path.trim().trim_matches('"').to_string();
+        let import = match serp::import_file(std::path::Path::new(&path)) {
+            Ok(import) => import,
+            Err(err) => {
+                self.serp_status = Some(Err(err.to_string()));
+                return;
+            }
+        };
+        let Some(page_query) = import.query else {
+            self.serp_status = Some(Err("la page ne dit pas quelle requête l'a produite".to_string()));
+            return;
+        };
+
+        let key = canonical_key(&page_query);
+        let entry = self
+            .history
+            .iter()
+            .find(|entry| {
+                let parsed = parser::parse(&entry.query);
+                canonical_key(&entry.query) == key
+                    || self.engines.iter().any(|engine| {
+                        canonical_key(&translate::translate(&parsed, engine.as_ref()).query) == key
+                    })
+            })
+            .cloned()
+            .unwrap_or(HistoryEntry {
+                query: page_query,
+                template: None,
+            });
+        let dork = entry.query;
+
+        self.serp_status = Some(Ok(format!(
+            "{} résultats {} importés pour « {} »",
+            import.results.len(),
+            import.engine,
+            dork
+        )));
+        self.record_results(&dork, import.results);
+        self.query = dork.clone();
+        self.apply_query_string(&dork);
+        self.source_template = entry.template;
+    }
+
+    fn apply_query_string(&mut self, query: &str) {
+        self.data = DorkData::from_query(&parser::parse(query));
     }
 
     fn default() -> Self {
@@ -147,118 +1179,587 @@ impl DorkApp {
             data: Default::default(),
             query: "".to_string(),
             history: vec![],
-            selected_template: 0,
+            selected_template: None,
+            source_template: None,
             selected_history: 0,
-            available_operators: vec![],
             selected_category: "".to_string(),
+            dates_in_url: false,
+            over_limit: false,
+            split_queries: vec![],
+            matching_template: None,
+            templates: vec![],
+            template_errors: vec![],
+            dialect: Dialect::Web,
+            engines: engines::all_engines(&[]),
+            selected_engine: 0,
+            settings: Settings::default(),
+            search_options: SearchOptions::default(),
+            cse_max_results: 30,
+            cse_query: "".to_string(),
+            cse_results: vec![],
+            cse_error: None,
+            cse_receiver: None,
+            results: ResultStore::default(),
+            result_filter: None,
+            serp_path: "".to_string(),
+            serp_status: None,
+            template_name: "".to_string(),
+            template_category: "".to_string(),
+            template_status: None,
+            ghdb_path: "".to_string(),
+            ghdb_status: None,
+            ghdb_failures: vec![],
+            param_prompt: None,
+            param_errors: vec![],
+            bulk_queries: vec![],
+            bulk_errors: vec![],
         }
     }
 }
 
 impl eframe::App for DorkApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        self.poll_cse();
+
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("🔍 Google Dork Builder");
-            
-            let previous = self.selected_template;
-            let prev_history = self.selected_history;
-            let prev_category = self.selected_category.clone();
-            
-            ui.horizontal(|ui| {
-                ui.label("Catégorie:");
-                egui::ComboBox::from_id_salt("category_select")
-                    .selected_text(&self.selected_category)
-                    .show_ui(ui, |ui| {
-                        for category in unique_categories(TEMPLATES) {
-                            ui.selectable_value(&mut self.selected_category, category.clone(), category);
-                            if self.selected_category != prev_category {
-                                self.selected_template = 0;
+
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                let previous = self.selected_template.clone();
+                let prev_history = self.selected_history;
+
+                ui.horizontal(|ui| {
+                    ui.label("Mode :");
+                    for dialect in Dialect::ALL {
+                        if ui.selectable_label(self.dialect == *dialect, dialect.label()).clicked() {
+                            self.set_dialect(*dialect);
+                        }
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Catégorie:");
+                    egui::ComboBox::from_id_salt("category_select")
+                        .selected_text(&self.selected_category)
+                        .show_ui(ui, |ui| {
+                            let mut categories = unique_categories(&self.templates);
+                            if !self.settings.favourite_templates.is_empty() {
+                                categories.insert(0, FAVOURITES.to_string());
+                            }
+                            for category in categories {
+                                ui.selectable_value(&mut self.selected_category, category.clone(), category);
+                            }
+                        });
+                });
+
+                let filtered_templates: Vec<(String, String)> = if self.selected_category == FAVOURITES {
+                    self.templates
+                        .iter()
+                        .filter(|tpl| self.settings.favourite_templates.contains(&tpl.id))
+                        .map(|tpl| (tpl.id.clone(), tpl.name.clone()))
+                        .collect()
+                } else {
+                    filter_dorks_by_category(&self.templates, &self.selected_category)
+                        .into_iter()
+                        .map(|tpl| (tpl.id.clone(), tpl.name.clone()))
+                        .collect()
+                };
+                let selected_name = self
+                    .selected_template
+                    .as_deref()
+                    .and_then(|id| self.template(id))
+                    .map(|tpl| tpl.name.clone())
+                    .unwrap_or_else(|| "Aucun".to_string());
+
+                let mut favourite_toggled = None;
+                ui.horizontal(|ui| {
+                    ui.label("Template:");
+                    egui::ComboBox::from_id_salt("template_select")
+                        .selected_text(selected_name)
+                        .show_ui(ui, |ui| {
+                            for (id, name) in &filtered_templates {
+                                ui.selectable_value(&mut self.selected_template, Some(id.clone()), name);
+                            }
+                        });
+                    if let Some(id) = &self.selected_template {
+                        let favourite = self.settings.favourite_templates.contains(id);
+                        let star = if favourite { "⭐" } else { "☆" };
+                        if ui.button(star).on_hover_text("Favori").clicked() {
+                            favourite_toggled = Some(id.clone());
+                        }
+                    }
+                    if ui.button("↻").on_hover_text("Recharger les fichiers de templates").clicked() {
+                        self.load_templates();
+                    }
+                });
+                for error in &self.template_errors {
+                    ui.colored_label(egui::Color32::ORANGE, format!("⚠ {}", error));
+                }
+
+                let mut chosen = None;
+                if let Some(tpl) = self.selected_template.as_deref().and_then(|id| self.template(id)) {
+                    if !tpl.variants.is_empty() {
+                        ui.horizontal(|ui| {
+                            ui.label("Variante :");
+                            for dialect in tpl.dialects() {
+                                if ui.selectable_label(self.dialect == dialect, dialect.label()).clicked() {
+                                    chosen = Some((tpl.id.clone(), Some(dialect)));
+                                }
+                            }
+                        });
+                    }
+                    if let Some(ghdb_id) = tpl.ghdb_id {
+                        ui.horizontal(|ui| {
+                            ui.hyperlink_to(format!("GHDB-{}", ghdb_id), format!("{}{}", ghdb::ENTRY_URL, ghdb_id));
+                            if !tpl.date.is_empty() {
+                                ui.label(format!("du {}", tpl.date));
+                            }
+                            if !tpl.author.is_empty() {
+                                ui.label(format!("par {}", tpl.author));
+                            }
+                        });
+                    }
+                    let related: Vec<&DorkTemplate> =
+                        tpl.see_also.iter().filter_map(|id| self.template(id)).collect();
+                    if !related.is_empty() {
+                        ui.horizontal_wrapped(|ui| {
+                            ui.label("Voir aussi :");
+                            for other in related {
+                                if ui.link(&other.name).clicked() {
+                                    chosen = Some((other.id.clone(), None));
+                                }
+                            }
+                        });
+                    }
+                }
+
+                let mut template_action = None;
+                egui::CollapsingHeader::new("🗂 Gérer les templates").show(ui, |ui| {
+                    egui::Grid::new("template_editor").num_columns(2).show(ui, |ui| {
+                        ui.label("Nom :");
+                        ui.text_edit_singleline(&mut self.template_name);
+                        ui.end_row();
+
+                        ui.label("Catégorie :");
+                        ui.text_edit_singleline(&mut self.template_category);
+                        ui.end_row();
+                    });
+                    let selected = self
+                        .selected_template
+                        .as_deref()
+                        .and_then(|id| self.template(id))
+                        .map(|tpl| (tpl.id.clone(), tpl.source.is_some()));
+                    ui.horizontal(|ui| {
+                        if ui.button("➕ Créer depuis le formulaire").clicked() {
+                            template_action = Some(TemplateAction::Create);
+                        }
+                        if let Some((id, editable)) = &selected {
+                            if ui
+                                .add_enabled(*editable, egui::Button::new("💾 Enregistrer les modifications"))
+                                .clicked()
+                            {
+                                template_action = Some(TemplateAction::Update(id.clone()));
+                            }
+                            if ui.button("⧉ Dupliquer").clicked() {
+                                template_action = Some(TemplateAction::Duplicate(id.clone()));
+                            }
+                            if ui.add_enabled(*editable, egui::Button::new("🗑 Supprimer")).clicked() {
+                                template_action = Some(TemplateAction::Delete(id.clone()));
                             }
                         }
                     });
-            });
+                    if let Some((_, false)) = selected {
+                        ui.label("Template intégré, en lecture seule : dupliquez-le pour le modifier.");
+                    }
+                    if self.search_options != self.settings.search_options {
+                        ui.label("Les options de recherche en cours seront enregistrées avec le template.");
+                    }
+                    match &self.template_status {
+                        Some(Ok(message)) => {
+                            ui.label(format!("✔ {}", message));
+                        }
+                        Some(Err(message)) => {
+                            ui.colored_label(egui::Color32::RED, format!("⛔ {}", message));
+                        }
+                        None => {}
+                    }
+                });
 
-            let filtered_templates = filter_dorks_by_category(TEMPLATES, &self.selected_category);
+                egui::CollapsingHeader::new("📚 Importer la Google Hacking Database").show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label("Export GHDB (CSV, XML, JSON) :");
+                        ui.text_edit_singleline(&mut self.ghdb_path);
+                        if ui.button("Importer").clicked() {
+                            self.import_ghdb();
+                        }
+                    });
+                    match &self.ghdb_status {
+                        Some(Ok(message)) => {
+                            ui.label(format!("✔ {}", message));
+                        }
+                        Some(Err(message)) => {
+                            ui.colored_label(egui::Color32::RED, format!("⛔ {}", message));
+                        }
+                        None => {}
+                    }
+                    if !self.ghdb_failures.is_empty() {
+                        egui::CollapsingHeader::new(format!("Dorks non importés ({})", self.ghdb_failures.len()))
+                            .show(ui, |ui| {
+                                egui::ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                                    for failure in &self.ghdb_failures {
+                                        ui.colored_label(egui::Color32::ORANGE, format!("⚠ {}", failure));
+                                    }
+                                });
+                            });
+                    }
+                });
+                if let Some(action) = template_action {
+                    self.edit_templates(action);
+                }
 
-            ui.horizontal(|ui| {
-                ui.label("Template:");
-                egui::ComboBox::from_id_salt("template_select")
-                    .selected_text(
-                        filtered_templates
-                            .get(self.selected_template)
-                            .map(|tpl| tpl.name)
-                            .unwrap_or("Aucun"),
-                    )
-                    .show_ui(ui, |ui| {
-                        for (i, tpl) in filtered_templates.iter().enumerate() {
-                            ui.selectable_value(&mut self.selected_template, i, tpl.name);
+                let mut instantiate = false;
+                egui::CollapsingHeader::new("🧩 Instancier tous les templates de la liste").show(ui, |ui| {
+                    let mut params: Vec<TemplateParam> = vec![];
+                    for param in filtered_templates
+                        .iter()
+                        .filter_map(|(id, _)| self.template(id))
+                        .flat_map(DorkTemplate::params)
+                    {
+                        if !params.iter().any(|known| known.name == param.name) {
+                            params.push(param);
+                        }
+                    }
+                    params_ui(ui, &params, &mut self.settings.param_values);
+                    ui.horizontal(|ui| {
+                        instantiate = ui.button("🧩 Générer les requêtes").clicked();
+                        if !self.bulk_queries.is_empty() && ui.button("📋 Tout copier").clicked() {
+                            let all: Vec<&str> = self.bulk_queries.iter().map(|(_, query)| query.as_str()).collect();
+                            copy_to_clipboard(&all.join("\n"));
                         }
                     });
-            });
+                    for error in &self.bulk_errors {
+                        ui.colored_label(egui::Color32::RED, format!("⛔ {}", error));
+                    }
+                    for (name, query) in &self.bulk_queries {
+                        ui.horizontal(|ui| {
+                            if ui.small_button("📋").clicked() {
+                                copy_to_clipboard(query);
+                            }
+                            if ui.small_button("🌐").clicked() {
+                                let _ = open::that(self.search_url(query));
+                            }
+                            ui.label(query).on_hover_text(name);
+                        });
+                    }
+                });
+                if instantiate {
+                    let ids: Vec<String> = filtered_templates.iter().map(|(id, _)| id.clone()).collect();
+                    self.instantiate_all(&ids);
+                }
 
-            if self.selected_template != previous {
-                self.apply_template(self.selected_template);
-                self.generate_query();
-            }
+                if let Some(id) = favourite_toggled {
+                    let favourites = &mut self.settings.favourite_templates;
+                    match favourites.iter().position(|favourite| *favourite == id) {
+                        Some(index) => {
+                            favourites.remove(index);
+                        }
+                        None => favourites.push(id),
+                    }
+                    let _ = self.save_settings();
+                }
+                let mut applied = None;
+                if let Some((id, dialect)) = chosen {
+                    if let Some(dialect) = dialect {
+                        self.set_dialect(dialect);
+                    }
+                    applied = Some(id);
+                } else if self.selected_template != previous {
+                    applied = self.selected_template.clone();
+                }
+                if let Some(id) = applied {
+                    self.apply_template(&id);
+                    if self.param_prompt.is_none() {
+                        self.generate_query();
+                    }
+                }
 
-            ui.separator();
+                ui.separator();
 
-            ui.label("Opérateur logique (ex: AND / OR / -) :");
-            ui.text_edit_singleline(&mut self.data.operator);
+                // Fields filled in another mode stay visible until emptied.
+                let operators = self.dialect.operators();
+                let shown: Vec<Operator> = Operator::ALL
+                    .iter()
+                    .copied()
+                    .filter(|op| {
+                        operators.contains(op)
+                            || self.data.fields.get(op).is_some_and(|field| field.clauses(*op).next().is_some())
+                    })
+                    .collect();
+                for operator in shown.iter().filter(|op| !op.is_advanced()) {
+                    field_ui(ui, *operator, self.data.fields.entry(*operator).or_default());
+                }
+                if shown.iter().any(|op| op.is_advanced()) {
+                    egui::CollapsingHeader::new("Opérateurs avancés").show(ui, |ui| {
+                        for operator in shown.iter().filter(|op| op.is_advanced()) {
+                            field_ui(ui, *operator, self.data.fields.entry(*operator).or_default());
+                        }
+                    });
+                }
 
-            ui.label("site:");
-            ui.text_edit_singleline(&mut self.data.site);
+                ui.horizontal(|ui| {
+                    ui.label("Groupes :");
+                    if ui.small_button("➕ Groupe").clicked() {
+                        self.data.groups.push(Group {
+                            items: vec![GroupItem::Clause(None, FieldValue::default())],
+                            any: true,
+                        });
+                    }
+                });
+                let mut removed = None;
+                for (i, group) in self.data.groups.iter_mut().enumerate() {
+                    if ui.push_id(("group", i), |ui| group_ui(ui, group, self.dialect.operators())).inner {
+                        removed = Some(i);
+                    }
+                }
+                if let Some(i) = removed {
+                    self.data.groups.remove(i);
+                }
 
-            ui.label("inurl:");
-            ui.text_edit_singleline(&mut self.data.inurl);
+                if self.dialect == Dialect::Web {
+                    ui.checkbox(
+                        &mut self.dates_in_url,
+                        "Envoyer la période dans l'URL (Google, Bing) plutôt que dans la requête",
+                    );
+                }
 
-            ui.label("intitle:");
-            ui.text_edit_singleline(&mut self.data.intitle);
+                ui.label("Autres termes :");
+                ui.text_edit_singleline(&mut self.data.keywords);
 
-            ui.label("filetype:");
-            ui.text_edit_singleline(&mut self.data.filetype);
+                for issue in lint::lint(&self.data.to_query()) {
+                    let color = match issue.severity {
+                        lint::Severity::Error => egui::Color32::RED,
+                        lint::Severity::Warning => egui::Color32::ORANGE,
+                    };
+                    ui.colored_label(color, issue.to_string());
+                }
 
-            ui.label("intext:");
-            ui.text_edit_singleline(&mut self.data.intext);
+                if ui.button("🔧 Générer la requête").clicked() {
+                    self.generate_query();
+                }
 
-            if ui.button("🔧 Générer la requête").clicked() {
-                self.generate_query();
-            }
+                ui.separator();
 
-            ui.separator();
+                ui.label("🕓 Historique des requêtes :");
+                egui::ComboBox::from_id_salt("history_select")
+                    .selected_text(
+                        self.history
+                            .get(self.selected_history)
+                            .map(|entry| entry.query.as_str())
+                            .unwrap_or(""),
+                    )
+                    .show_ui(ui, |ui| {
+                        for (i, entry) in self.history.iter().enumerate() {
+                            ui.selectable_value(&mut self.selected_history, i, &entry.query);
+                        }
+                    });
 
-            ui.label("🕓 Historique des requêtes :");
-            egui::ComboBox::from_id_salt("history_select")
-                .selected_text(self.history.get(self.selected_history).unwrap_or(&"".to_string()))
-                .show_ui(ui, |ui| {
-                    for (i, entry) in self.history.iter().enumerate() {
-                        ui.selectable_value(&mut self.selected_history, i, entry);
+                if self.selected_history != prev_history
+                    && let Some(entry) = self.history.get(self.selected_history)
+                {
+                    let entry = entry.clone();
+                    self.apply_query_string(&entry.query);
+                    self.source_template = entry.template;
+                    self.generate_query();
+                }
+
+                ui.label("🔎 Requête générée :");
+                ui.text_edit_multiline(&mut self.query);
+                if let Some(tpl) = self.source_template.as_deref().and_then(|id| self.template(id)) {
+                    ui.label(format!("↳ Issue du template : {}", tpl.name));
+                }
+                if let Some(name) = &self.matching_template {
+                    ui.label(format!("≡ Équivalente au template : {}", name));
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label("Moteur :");
+                    egui::ComboBox::from_id_salt("engine_select")
+                        .selected_text(self.engine().name())
+                        .show_ui(ui, |ui| {
+                            for (i, engine) in self.engines.iter().enumerate() {
+                                if engine.dialect() == self.dialect {
+                                    ui.selectable_value(&mut self.selected_engine, i, engine.name());
+                                }
+                            }
+                        });
+                    if ui
+                        .button("↻")
+                        .on_hover_text(format!("Recharger les moteurs personnalisés de {}", SETTINGS_FILE))
+                        .clicked()
+                    {
+                        self.load_settings();
                     }
                 });
-            
-            if self.selected_history != prev_history {
-                if let Some(query) = self.history.get(self.selected_history) {
-                    let query = query.clone(); // clone la String
-                    self.apply_query_string(&query);
-                    self.generate_query();
+
+                egui::CollapsingHeader::new("⚙ Options de recherche").show(ui, |ui| {
+                    if self.dialect != Dialect::Web {
+                        ui.label("Ces options ne concernent que les moteurs web.");
+                    }
+                    options_ui(ui, &mut self.search_options);
+                    ui.horizontal(|ui| {
+                        if ui.button("💾 Enregistrer comme défaut").clicked() {
+                            self.settings.search_options = self.search_options.clone();
+                            let _ = self.save_settings();
+                        }
+                        if ui.button("↺ Rétablir le défaut").clicked() {
+                            self.search_options = self.settings.search_options.clone();
+                        }
+                    });
+                });
+
+                let translation = translate::translate(&parser::parse(&self.query), self.engine());
+                if translation.query != self.query || !translation.notes.is_empty() {
+                    ui.horizontal(|ui| {
+                        ui.label(format!("Pour {} :", self.engine().name()));
+                        if ui.small_button("📋").clicked() {
+                            copy_to_clipboard(&translation.query);
+                        }
+                        ui.label(&translation.query);
+                    });
+                    for note in &translation.notes {
+                        ui.colored_label(egui::Color32::ORANGE, format!("⚠ {}", note));
+                    }
                 }
-            }
 
-            ui.label("🔎 Requête générée :");
-            ui.text_edit_multiline(&mut self.query);
+                let key = canonical_key(&self.query);
+                if let Some(dork) = self.results.get_mut(&key) {
+                    let filter = &mut self.result_filter;
+                    if results_ui(ui, dork, filter) {
+                        let _ = self.results.save(RESULTS_FILE);
+                    }
+                }
 
-            ui.horizontal(|ui| {
-                if ui.button("📋 Copier").clicked() {
-                    let mut ctx = ClipboardContext::new().unwrap();
-                    let _ = ctx.set_contents(self.query.clone());
+                ui.horizontal(|ui| {
+                    if ui.button("📋 Copier").clicked() {
+                        copy_to_clipboard(&self.query);
+                    }
+
+                    if ui.button("🌐 Ouvrir dans le navigateur").clicked() {
+                        let _ = open::that(self.search_url(&self.query));
+                    }
+                });
+
+                if self.dialect == Dialect::Web {
+                    egui::CollapsingHeader::new("🗄 Archives (Wayback, Common Crawl)").show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("Index Common Crawl :");
+                            let edit = ui.add(
+                                egui::TextEdit::singleline(&mut self.settings.common_crawl_index)
+                                    .hint_text(archive::COMMON_CRAWL_INDEX),
+                            );
+                            if edit.lost_focus() {
+                                let _ = self.save_settings();
+                            }
+                        });
+                        let lookups =
+                            archive::lookups(&parser::parse(&self.query), &self.settings.common_crawl_index);
+                        if lookups.is_empty() {
+                            ui.label("Ajoutez un site: pour chercher dans les archives.");
+                        }
+                        for lookup in lookups {
+                            ui.horizontal(|ui| {
+                                if ui.small_button("📋").clicked() {
+                                    copy_to_clipboard(&lookup.url);
+                                }
+                                if ui.small_button("🌐").clicked() {
+                                    let _ = open::that(&lookup.url);
+                                }
+                                ui.label(&lookup.label);
+                            });
+                        }
+                    });
+
+                    egui::CollapsingHeader::new("🤖 Exécuter via l'API Custom Search").show(ui, |ui| {
+                        cse_config_ui(ui, &mut self.settings.cse);
+                        ui.horizontal(|ui| {
+                            if ui.button("💾 Enregistrer").clicked() {
+                                let _ = self.save_settings();
+                            }
+                            ui.label("Résultats max :");
+                            ui.add(
+                                egui::DragValue::new(&mut self.cse_max_results)
+                                    .range(cse::PAGE_SIZE..=cse::MAX_RESULTS),
+                            );
+                            let running = self.cse_receiver.is_some();
+                            if ui
+                                .add_enabled(!running && !self.query.is_empty(), egui::Button::new("▶ Exécuter"))
+                                .clicked()
+                            {
+                                self.run_cse(ui.ctx());
+                            }
+                            if running {
+                                ui.spinner();
+                            }
+                        });
+                        if let Some(error) = &self.cse_error {
+                            ui.colored_label(egui::Color32::RED, format!("⛔ {}", error));
+                        }
+                        if self.cse_receiver.is_some() {
+                            ui.label(format!("{} résultats reçus…", self.cse_results.len()));
+                        }
+                    });
+
+                    egui::CollapsingHeader::new("📥 Importer une page de résultats enregistrée").show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("Fichier HTML :");
+                            ui.text_edit_singleline(&mut self.serp_path);
+                            if ui.button("Importer").clicked() {
+                                self.import_serp();
+                            }
+                        });
+                        match &self.serp_status {
+                            Some(Ok(message)) => {
+                                ui.label(format!("✔ {}", message));
+                            }
+                            Some(Err(message)) => {
+                                ui.colored_label(egui::Color32::RED, format!("⛔ {}", message));
+                            }
+                            None => {}
+                        }
+                    });
+                }
+
+                if self.over_limit {
+                    ui.horizontal(|ui| {
+                        ui.colored_label(
+                            egui::Color32::ORANGE,
+                            "⚠ Requête trop longue pour le moteur de recherche",
+                        );
+                        if ui.button("✂ Découper").clicked() {
+                            self.split_queries = split::split(&parser::parse(&self.query), self.engine())
+                                .iter()
+                                .map(Query::to_string)
+                                .collect();
+                        }
+                    });
                 }
 
-                if ui.button("🌐 Ouvrir dans le navigateur").clicked() {
-                    let encoded = urlencoding::encode(&self.query);
-                    let _ = open::that(format!("https://www.google.com/search?q={}", encoded));
+                for (i, part) in self.split_queries.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.label(format!("{}.", i + 1));
+                        if ui.small_button("📋").clicked() {
+                            copy_to_clipboard(part);
+                        }
+                        if ui.small_button("🌐").clicked() {
+                            let _ = open::that(self.search_url(part));
+                        }
+                        ui.label(part);
+                    });
                 }
             });
         });
+
+        self.params_window(ctx);
     }
 }
 
@@ -266,6 +1767,9 @@ fn main() -> eframe::Result<()> {
     let options = eframe::NativeOptions::default();
     let mut app = DorkApp::default();
     app.load_history();
+    app.load_settings();
+    app.load_templates();
+    app.results = ResultStore::load(RESULTS_FILE);
     eframe::run_native(
         "Google Dork Builder",
         options,
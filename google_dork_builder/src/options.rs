@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeRange {
+    #[default]
+    Any,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl TimeRange {
+    pub const ALL: &'static [TimeRange] = &[
+        TimeRange::Any,
+        TimeRange::Hour,
+        TimeRange::Day,
+        TimeRange::Week,
+        TimeRange::Month,
+        TimeRange::Year,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TimeRange::Any => "Toutes les dates",
+            TimeRange::Hour => "Dernière heure",
+            TimeRange::Day => "Dernières 24 h",
+            TimeRange::Week => "Dernière semaine",
+            TimeRange::Month => "Dernier mois",
+            TimeRange::Year => "Dernière année",
+        }
+    }
+
+    /// The one-letter code shared by Google's `qdr:` and DuckDuckGo's `df=`.
+    pub fn code(self) -> Option<&'static str> {
+        match self {
+            TimeRange::Any => None,
+            TimeRange::Hour => Some("h"),
+            TimeRange::Day => Some("d"),
+            TimeRange::Week => Some("w"),
+            TimeRange::Month => Some("m"),
+            TimeRange::Year => Some("y"),
+        }
+    }
+}
+
+/// Search settings sent as URL parameters rather than in the query. Empty strings and a zero
+/// `num` leave the engine default.
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct SearchOptions {
+    /// Interface language, e.g. `fr` (`hl`).
+    pub language: String,
+    /// Country to search from, e.g. `fr` (`gl`).
+    pub region: String,
+    /// Only return pages in this language, e.g. `fr` (`lr=lang_fr`).
+    pub results_language: String,
+    pub num: u32,
+    /// Keep results Google would hide as near duplicates (`filter=0`).
+    pub no_filter: bool,
+    pub safe_off: bool,
+    pub verbatim: bool,
+    pub time_range: TimeRange,
+}
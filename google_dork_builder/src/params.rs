@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// What a placeholder stands for, which decides how its value is checked.
+#[derive(Default, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ParamKind {
+    #[default]
+    Text,
+    Domain,
+    Org,
+    Keyword,
+}
+
+impl ParamKind {
+    /// The kind a placeholder has when no template declares it: the one named like it, if any.
+    fn from_name(name: &str) -> Self {
+        match name {
+            "domain" => ParamKind::Domain,
+            "org" => ParamKind::Org,
+            "keyword" => ParamKind::Keyword,
+            _ => ParamKind::Text,
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            ParamKind::Text => "",
+            ParamKind::Domain => "Domaine visé, sans schéma (ex. example.com)",
+            ParamKind::Org => "Nom de l'organisation",
+            ParamKind::Keyword => "Mot-clé ou expression",
+        }
+    }
+}
+
+/// A `{{name}}` placeholder of a template.
+#[derive(Default, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct TemplateParam {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: ParamKind,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub default: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub description: String,
+}
+
+impl TemplateParam {
+    /// The parameter of a placeholder used without being declared.
+    pub fn implicit(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            kind: ParamKind::from_name(name),
+            ..Default::default()
+        }
+    }
+
+    pub fn description(&self) -> &str {
+        if self.description.is_empty() {
+            self.kind.description()
+        } else {
+            &self.description
+        }
+    }
+
+    /// The value to put in place of the placeholder: `value`, or the default when it is empty,
+    /// cleaned up for the kind of parameter.
+    pub fn check(&self, value: &str) -> Result<String, String> {
+        let value = match value.trim() {
+            "" => self.default.trim(),
+            value => value,
+        };
+        if value.is_empty() {
+            return Err(format!("{} : valeur requise", self.name));
+        }
+        match self.kind {
+            ParamKind::Domain => {
+                let domain = value
+                    .trim_start_matches("https://")
+                    .trim_start_matches("http://")
+                    .trim_end_matches('/');
+                if domain.is_empty() || domain.contains(char::is_whitespace) {
+                    return Err(format!("{} : « {} » n'est pas un domaine", self.name, value));
+                }
+                Ok(domain.to_string())
+            }
+            ParamKind::Text | ParamKind::Org | ParamKind::Keyword => Ok(value.to_string()),
+        }
+    }
+}
+
+/// The names of the `{{name}}` placeholders in `text`, in order of first use.
+pub fn placeholders(text: &str) -> Vec<String> {
+    let mut names: Vec<String> = vec![];
+    let mut rest = text;
+    while let Some((_, name, after)) = next_placeholder(rest) {
+        if !names.iter().any(|known| known == name) {
+            names.push(name.to_string());
+        }
+        rest = after;
+    }
+    names
+}
+
+/// Replaces the placeholders of `text` that have a value in `values`; the others are left as
+/// they are.
+pub fn substitute(text: &str, values: &BTreeMap<String, String>) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some((before, name, after)) = next_placeholder(rest) {
+        result.push_str(before);
+        match values.get(name) {
+            Some(value) => result.push_str(value),
+            None => result.push_str(&rest[before.len()..rest.len() - after.len()]),
+        }
+        rest = after;
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Splits `text` around its first placeholder: the text before, the name and the text after.
+/// Names are made of ASCII letters, digits and `_`.
+fn next_placeholder(text: &str) -> Option<(&str, &str, &str)> {
+    let mut offset = 0;
+    loop {
+        let start = text[offset..].find("{{")? + offset;
+        let end = text[start + 2..].find("}}")? + start + 2;
+        let name = text[start + 2..end].trim();
+        if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Some((&text[..start], name, &text[end + 2..]));
+        }
+        offset = start + 2;
+    }
+}
+
+/// Checks a value for each parameter, taken from `values` or else from the parameter's default.
+/// Returns the values to substitute, or one message per parameter left without a valid value.
+pub fn bind(
+    params: &[TemplateParam],
+    values: &BTreeMap<String, String>,
+) -> Result<BTreeMap<String, String>, Vec<String>> {
+    let mut bound = BTreeMap::new();
+    let mut errors = vec![];
+    for param in params {
+        let value = values.get(&param.name).map(String::as_str).unwrap_or_default();
+        match param.check(value) {
+            Ok(value) => {
+                bound.insert(param.name.clone(), value);
+            }
+            Err(error) => errors.push(error),
+        }
+    }
+    if errors.is_empty() { Ok(bound) } else { Err(errors) }
+}
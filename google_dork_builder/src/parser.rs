@@ -0,0 +1,298 @@
+use crate::query::{Clause, Operator, Query, Term, is_range};
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
+enum Token {
+    /// A `-` right before `(`, negating the group.
+    Not,
+    Open,
+    Close,
+    Or,
+    And,
+    Clause(Clause),
+}
+
+/// Parses a dork written in Google syntax. The parser never fails: stray parentheses and
+/// dangling `OR`s are skipped and unknown `name:value` pairs are kept as bare words. A negated
+/// group `-(a OR b)` is read with the negation distributed over it, as `-a -b`.
+///
+/// `parse(&query.to_string()) == query` holds for queries built through `Query::all`/`any` from
+/// clauses whose terms come from `Term::word`, `Term::phrase` and `Term::words`.
+pub fn parse(input: &str) -> Query {
+    let mut tokens = tokenize(input).into_iter().peekable();
+    parse_all(&mut tokens, 0)
+}
+
+fn parse_all(tokens: &mut Peekable<IntoIter<Token>>, depth: usize) -> Query {
+    let mut children = vec![];
+    while let Some(token) = tokens.peek() {
+        match token {
+            Token::Close if depth > 0 => break,
+            Token::Close | Token::And | Token::Or => {
+                tokens.next();
+            }
+            Token::Not | Token::Open | Token::Clause(_) => children.push(parse_any(tokens, depth)),
+        }
+    }
+    Query::all(children)
+}
+
+fn parse_any(tokens: &mut Peekable<IntoIter<Token>>, depth: usize) -> Query {
+    let mut children = vec![parse_primary(tokens, depth)];
+    while matches!(tokens.peek(), Some(Token::Or)) {
+        tokens.next();
+        if !matches!(tokens.peek(), Some(Token::Not | Token::Open | Token::Clause(_))) {
+            break;
+        }
+        children.push(parse_primary(tokens, depth));
+    }
+    Query::any(children)
+}
+
+fn parse_primary(tokens: &mut Peekable<IntoIter<Token>>, depth: usize) -> Query {
+    match tokens.next() {
+        Some(Token::Clause(clause)) => Query::Clause(clause),
+        Some(Token::Open) => {
+            let inner = parse_all(tokens, depth + 1);
+            if matches!(tokens.peek(), Some(Token::Close)) {
+                tokens.next();
+            }
+            inner
+        }
+        // The tokenizer only emits `Not` right before `Open`.
+        Some(Token::Not) => negate(parse_primary(tokens, depth)),
+        _ => Query::default(),
+    }
+}
+
+/// Pushes a negation down to the clauses: `-(a OR b)` is `-a -b` and `-(a b)` is `-a OR -b`.
+fn negate(query: Query) -> Query {
+    match query {
+        query if query.is_empty() => Query::default(),
+        Query::Clause(clause) => Query::Clause(clause.negate()),
+        Query::And(children) => Query::any(children.into_iter().map(negate).collect()),
+        Query::Or(children) => Query::all(children.into_iter().map(negate).collect()),
+    }
+}
+
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '(' | ')' | '|' | '"')
+}
+
+fn tokenize(input: &str) -> Vec<Token> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::Open);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::Close);
+                i += 1;
+            }
+            '|' => {
+                tokens.push(Token::Or);
+                i += 1;
+            }
+            '-' if chars.get(i + 1) == Some(&'(') => {
+                tokens.push(Token::Not);
+                i += 1;
+            }
+            _ => {
+                let negated = chars[i] == '-' && chars.get(i + 1).is_some_and(|&c| !is_separator(c) || c == '"');
+                if negated {
+                    i += 1;
+                }
+                let (token, next) = read_term(&chars, i, negated);
+                match (tokens.last_mut(), token) {
+                    (Some(Token::Clause(previous)), Token::Clause(clause)) if takes(previous, &clause) => {
+                        if let Term::Words(words) = &mut previous.term {
+                            words.push(clause.term);
+                        }
+                    }
+                    (_, token) => tokens.push(token),
+                }
+                i = next;
+            }
+        }
+    }
+    tokens
+}
+
+/// Whether `clause` is one more word of a preceding `allin*` clause.
+fn takes(previous: &Clause, clause: &Clause) -> bool {
+    previous.operator.is_some_and(Operator::takes_words)
+        && clause.operator.is_none()
+        && !clause.negated
+        && matches!(clause.term, Term::Word(_) | Term::Phrase(_))
+}
+
+fn read_term(chars: &[char], start: usize, negated: bool) -> (Token, usize) {
+    if chars[start] == '"' {
+        let (term, next) = read_phrase(chars, start);
+        return (clause_token(None, term, negated), next);
+    }
+
+    let mut end = start;
+    while end < chars.len() && !is_separator(chars[end]) {
+        end += 1;
+    }
+    let word: String = chars[start..end].iter().collect();
+
+    if !negated {
+        match word.as_str() {
+            "OR" => return (Token::Or, end),
+            "AND" => return (Token::And, end),
+            _ => {}
+        }
+    }
+
+    if let Some((name, value)) = word.split_once(':')
+        && let Some(operator) = Operator::from_name(name)
+    {
+        let value = if !value.is_empty() {
+            Some((Term::Word(value.to_string()), end))
+        } else if chars.get(end) == Some(&'"') {
+            Some(read_phrase(chars, end))
+        } else {
+            None
+        };
+        if let Some((term, next)) = value {
+            let term = if operator.takes_words() { Term::Words(vec![term]) } else { term };
+            return (clause_token(Some(operator), term, negated), next);
+        }
+    }
+
+    if is_range(&word) {
+        return (clause_token(Some(Operator::Range), Term::Word(word), negated), end);
+    }
+    (clause_token(None, Term::Word(word), negated), end)
+}
+
+/// Reads a quoted phrase starting at `start`. Runs of quotes are treated as a single delimiter so
+/// that doubly quoted values such as `""view.shtml""` read back as `"view.shtml"`.
+fn read_phrase(chars: &[char], start: usize) -> (Term, usize) {
+    let mut i = start;
+    while chars.get(i) == Some(&'"') {
+        i += 1;
+    }
+    if i - start >= 2 && chars.get(i).is_none_or(|&c| is_separator(c)) {
+        return (Term::phrase(""), i);
+    }
+
+    let text_start = i;
+    while i < chars.len() && chars[i] != '"' {
+        i += 1;
+    }
+    let text: String = chars[text_start..i].iter().collect();
+    while chars.get(i) == Some(&'"') {
+        i += 1;
+    }
+    (Term::phrase(&text), i)
+}
+
+fn clause_token(operator: Option<Operator>, term: Term, negated: bool) -> Token {
+    let clause = Clause::new(operator, term);
+    Token::Clause(if negated { clause.negate() } else { clause })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use crate::query::{Clause, Operator, Query, Term};
+
+    fn clause(operator: Option<Operator>, term: Term) -> Query {
+        Clause::new(operator, term).into()
+    }
+
+    fn assert_round_trip(query: Query) {
+        assert_eq!(parse(&query.to_string()), query, "rendered as {}", query);
+    }
+
+    #[test]
+    fn quoted_values_round_trip() {
+        assert_round_trip(Clause::with_value(Operator::InTitle, "index of").into());
+        assert_round_trip(Query::all(vec![
+            clause(None, Term::word("two words")),
+            clause(None, Term::phrase("")),
+            clause(None, Term::word("OR")),
+            clause(None, Term::word("-dash")),
+            clause(None, Term::word("a|b")),
+        ]));
+    }
+
+    #[test]
+    fn operator_and_range_shaped_words_stay_words() {
+        assert_round_trip(clause(None, Term::word("site:x")));
+        assert_round_trip(clause(None, Term::word("InUrl:admin")));
+        assert_round_trip(clause(None, Term::word("100..200")));
+        assert_round_trip(Clause::with_value(Operator::Range, "100..200").into());
+        assert_round_trip(clause(None, Term::word("http://example.com")));
+    }
+
+    #[test]
+    fn doubled_quotes_from_history_read_as_one_phrase() {
+        let query = parse(r#"intitle:""view.shtml"" ""admin"""#);
+        assert_eq!(query.to_string(), r#"intitle:"view.shtml" "admin""#);
+        assert_round_trip(query);
+    }
+
+    #[test]
+    fn negation_round_trips() {
+        assert_round_trip(Clause::with_value(Operator::Site, "example.com").negate().into());
+        assert_round_trip(Clause::new(None, Term::phrase("log in")).negate().into());
+        assert_round_trip(Query::any(vec![
+            Clause::new(None, Term::word("a")).negate().into(),
+            Clause::new(None, Term::word("b")).negate().into(),
+        ]));
+    }
+
+    #[test]
+    fn negated_group_is_distributed() {
+        assert_eq!(parse("-(a OR b)").to_string(), "-a -b");
+        assert_eq!(parse("-(a b)").to_string(), "-a OR -b");
+        assert_eq!(
+            parse("-(inurl:a OR inurl:b) site:x.com").to_string(),
+            "(-inurl:a -inurl:b) site:x.com"
+        );
+        assert_eq!(parse("-(-a OR b)").to_string(), "a -b");
+        assert_eq!(parse("-()"), Query::default());
+    }
+
+    #[test]
+    fn or_and_pipe_are_the_same() {
+        assert_eq!(parse("a | b"), parse("a OR b"));
+        assert_eq!(parse("a|b"), parse("a OR b"));
+        assert_round_trip(parse("filetype:env OR filetype:ini | filetype:log"));
+    }
+
+    #[test]
+    fn nested_groups_round_trip() {
+        let query = parse("site:x.com (a OR (b c)) (d OR e) f");
+        assert_eq!(query.to_string(), "site:x.com (a OR (b c)) (d OR e) f");
+        assert_round_trip(query);
+    }
+
+    #[test]
+    fn allin_operators_take_the_following_words() {
+        let query = parse(r#"allintitle:admin "control panel" login"#);
+        assert_eq!(
+            query,
+            clause(
+                Some(Operator::AllInTitle),
+                Term::Words(vec![Term::word("admin"), Term::phrase("control panel"), Term::word("login")])
+            )
+        );
+        assert_round_trip(query);
+        assert_round_trip(Query::all(vec![
+            Clause::with_value(Operator::AllInTitle, "a").into(),
+            clause(None, Term::word("b")),
+        ]));
+        assert_round_trip(Clause::with_value(Operator::AllInUrl, "").into());
+    }
+}
@@ -0,0 +1,495 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Serialised under the name it is written with in queries, e.g. `inurl`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Operator {
+    Site,
+    InUrl,
+    InTitle,
+    FileType,
+    InText,
+    Ext,
+    AllInTitle,
+    AllInUrl,
+    AllInText,
+    InAnchor,
+    Related,
+    Cache,
+    Before,
+    After,
+    /// A numeric range such as `100..200`, written without any `name:` prefix.
+    Range,
+    Repo,
+    Org,
+    Path,
+    Language,
+    Filename,
+    Extension,
+    Port,
+    Product,
+    #[serde(rename = "http.title")]
+    HttpTitle,
+    Country,
+    Hostname,
+    Net,
+}
+
+impl Operator {
+    pub const ALL: &'static [Operator] = &[
+        Operator::Site,
+        Operator::InUrl,
+        Operator::InTitle,
+        Operator::FileType,
+        Operator::InText,
+        Operator::Ext,
+        Operator::AllInTitle,
+        Operator::AllInUrl,
+        Operator::AllInText,
+        Operator::InAnchor,
+        Operator::Related,
+        Operator::Cache,
+        Operator::Before,
+        Operator::After,
+        Operator::Range,
+        Operator::Repo,
+        Operator::Org,
+        Operator::Path,
+        Operator::Language,
+        Operator::Filename,
+        Operator::Extension,
+        Operator::Port,
+        Operator::Product,
+        Operator::HttpTitle,
+        Operator::Country,
+        Operator::Hostname,
+        Operator::Net,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Operator> {
+        Self::ALL
+            .iter()
+            .copied()
+            .filter(|operator| operator.has_prefix())
+            .find(|operator| operator.as_str().eq_ignore_ascii_case(name))
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Operator::Site => "site",
+            Operator::InUrl => "inurl",
+            Operator::InTitle => "intitle",
+            Operator::FileType => "filetype",
+            Operator::InText => "intext",
+            Operator::Ext => "ext",
+            Operator::AllInTitle => "allintitle",
+            Operator::AllInUrl => "allinurl",
+            Operator::AllInText => "allintext",
+            Operator::InAnchor => "inanchor",
+            Operator::Related => "related",
+            Operator::Cache => "cache",
+            Operator::Before => "before",
+            Operator::After => "after",
+            Operator::Range => "range",
+            Operator::Repo => "repo",
+            Operator::Org => "org",
+            Operator::Path => "path",
+            Operator::Language => "language",
+            Operator::Filename => "filename",
+            Operator::Extension => "extension",
+            Operator::Port => "port",
+            Operator::Product => "product",
+            Operator::HttpTitle => "http.title",
+            Operator::Country => "country",
+            Operator::Hostname => "hostname",
+            Operator::Net => "net",
+        }
+    }
+
+    pub fn has_prefix(self) -> bool {
+        self != Operator::Range
+    }
+
+    /// Whether values of this operator are rendered as quoted phrases by default.
+    pub fn quotes_value(self) -> bool {
+        matches!(
+            self,
+            Operator::InUrl | Operator::InTitle | Operator::InText | Operator::InAnchor
+        )
+    }
+
+    /// `allin*` operators apply to every word that follows them, up to the next operator.
+    pub fn takes_words(self) -> bool {
+        matches!(
+            self,
+            Operator::AllInTitle | Operator::AllInUrl | Operator::AllInText
+        )
+    }
+
+    pub fn is_advanced(self) -> bool {
+        matches!(
+            self,
+            Operator::Ext
+                | Operator::AllInTitle
+                | Operator::AllInUrl
+                | Operator::AllInText
+                | Operator::InAnchor
+                | Operator::Related
+                | Operator::Cache
+                | Operator::Before
+                | Operator::After
+                | Operator::Range
+        )
+    }
+}
+
+/// Whether `text` is a numeric range such as `100..200` or `$10..$50`.
+pub fn is_range(text: &str) -> bool {
+    fn is_number(text: &str) -> bool {
+        let digits = text.trim_start_matches(['$', '€', '£']);
+        digits.starts_with(|c: char| c.is_ascii_digit())
+            && digits.chars().all(|c| c.is_ascii_digit() || c == '.' || c == ',')
+    }
+    text.split_once("..")
+        .is_some_and(|(low, high)| is_number(low) && is_number(high))
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum Term {
+    Word(String),
+    Phrase(String),
+    /// The space separated words taken by an `allin*` operator.
+    Words(Vec<Term>),
+}
+
+impl Term {
+    /// Builds a bare word, falling back to a phrase when the text could not be read back as one
+    /// word: when it would split, or read as a negation, an `OR`, an operator or a range.
+    pub fn word(text: &str) -> Term {
+        let text = text.trim();
+        let needs_quotes = text.is_empty()
+            || text.contains(|c: char| c.is_whitespace() || matches!(c, '"' | '(' | ')' | '|'))
+            || text.starts_with('-')
+            || text == "OR"
+            || text == "AND"
+            || text
+                .split_once(':')
+                .is_some_and(|(name, _)| Operator::from_name(name).is_some())
+            || is_range(text);
+        if needs_quotes {
+            Term::phrase(text)
+        } else {
+            Term::Word(text.to_string())
+        }
+    }
+
+    pub fn phrase(text: &str) -> Term {
+        Term::Phrase(text.replace('"', "").trim().to_string())
+    }
+
+    /// Splits `text` on whitespace outside of quotes, e.g. `"index of" backup`.
+    pub fn words(text: &str) -> Term {
+        let mut words = vec![];
+        let mut current = String::new();
+        let mut in_quotes = false;
+        for c in text.chars() {
+            match c {
+                '"' => {
+                    in_quotes = !in_quotes;
+                    current.push(c);
+                }
+                c if c.is_whitespace() && !in_quotes => {
+                    words.push(std::mem::take(&mut current));
+                }
+                c => current.push(c),
+            }
+        }
+        words.push(current);
+
+        let words: Vec<Term> = words
+            .iter()
+            .filter(|word| !word.is_empty())
+            .map(|word| {
+                if word.starts_with('"') {
+                    Term::phrase(word)
+                } else {
+                    Term::word(word)
+                }
+            })
+            .collect();
+        // An `allin*` operator needs a value to be read back as one: `allintitle:""` at least.
+        if words.is_empty() {
+            return Term::Words(vec![Term::phrase("")]);
+        }
+        Term::Words(words)
+    }
+
+    fn canonical(&self) -> Term {
+        match self {
+            Term::Word(text) | Term::Phrase(text) => {
+                let text = text.to_lowercase();
+                if text.contains(char::is_whitespace) {
+                    Term::Phrase(text)
+                } else {
+                    Term::word(&text)
+                }
+            }
+            Term::Words(words) => Term::Words(words.iter().map(Term::canonical).collect()),
+        }
+    }
+
+    pub fn text(&self) -> String {
+        match self {
+            Term::Word(text) | Term::Phrase(text) => text.clone(),
+            Term::Words(_) => self.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Term::Word(text) => f.write_str(text),
+            Term::Phrase(text) => write!(f, "\"{}\"", text),
+            Term::Words(words) if words.is_empty() => f.write_str("\"\""),
+            Term::Words(words) => {
+                for (i, word) in words.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" ")?;
+                    }
+                    write!(f, "{}", word)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Clause {
+    pub operator: Option<Operator>,
+    pub term: Term,
+    pub negated: bool,
+}
+
+impl Clause {
+    pub fn new(operator: Option<Operator>, term: Term) -> Self {
+        Self {
+            operator,
+            term,
+            negated: false,
+        }
+    }
+
+    /// Builds a clause quoting the value the way `operator` usually expects it.
+    pub fn with_value(operator: Operator, value: &str) -> Self {
+        let term = if operator.quotes_value() {
+            Term::phrase(value)
+        } else if !operator.has_prefix() {
+            Term::Word(value.trim().to_string())
+        } else if operator.takes_words() {
+            Term::words(value)
+        } else {
+            Term::word(value)
+        };
+        Self::new(Some(operator), term)
+    }
+
+    pub fn negate(mut self) -> Self {
+        self.negated = !self.negated;
+        self
+    }
+}
+
+impl fmt::Display for Clause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negated {
+            f.write_str("-")?;
+        }
+        if let Some(operator) = self.operator.filter(|op| op.has_prefix()) {
+            write!(f, "{}:", operator)?;
+        }
+        write!(f, "{}", self.term)
+    }
+}
+
+/// A dork as a tree of clauses. Compound nodes built through `all`/`any` always hold at least two
+/// children, so an empty query is `And(vec![])` and a single clause is never wrapped.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum Query {
+    Clause(Clause),
+    And(Vec<Query>),
+    Or(Vec<Query>),
+}
+
+impl Default for Query {
+    fn default() -> Self {
+        Query::And(vec![])
+    }
+}
+
+impl Query {
+    pub fn all(children: Vec<Query>) -> Query {
+        Self::compound(children, Query::And)
+    }
+
+    pub fn any(children: Vec<Query>) -> Query {
+        Self::compound(children, Query::Or)
+    }
+
+    fn compound(mut children: Vec<Query>, build: fn(Vec<Query>) -> Query) -> Query {
+        children.retain(|child| !child.is_empty());
+        if children.len() == 1 {
+            children.remove(0)
+        } else {
+            build(children)
+        }
+    }
+
+    /// A normal form for comparing queries: nested lists flattened, children sorted and
+    /// deduplicated, values lowercased and single words unquoted. Only meant for comparisons, as
+    /// reordering can change what an `allin*` operator applies to.
+    pub fn canonical(&self) -> Query {
+        match self {
+            // A range is only read as one when bare.
+            Query::Clause(clause) if clause.operator == Some(Operator::Range) => self.clone(),
+            Query::Clause(clause) => Query::Clause(Clause {
+                term: clause.term.canonical(),
+                ..clause.clone()
+            }),
+            Query::And(children) => Query::all(Self::canonical_children(children, |q| match q {
+                Query::And(inner) => Some(inner),
+                _ => None,
+            })),
+            Query::Or(children) => Query::any(Self::canonical_children(children, |q| match q {
+                Query::Or(inner) => Some(inner),
+                _ => None,
+            })),
+        }
+    }
+
+    fn canonical_children(
+        children: &[Query],
+        same_kind: fn(&Query) -> Option<&Vec<Query>>,
+    ) -> Vec<Query> {
+        let mut flat = vec![];
+        for child in children.iter().map(Query::canonical) {
+            match same_kind(&child) {
+                Some(inner) => flat.extend(inner.iter().cloned()),
+                None => flat.push(child),
+            }
+        }
+        flat.sort_by_cached_key(Query::to_string);
+        flat.dedup();
+        flat
+    }
+
+    pub fn clauses(&self) -> Vec<&Clause> {
+        match self {
+            Query::Clause(clause) => vec![clause],
+            Query::And(children) | Query::Or(children) => {
+                children.iter().flat_map(Query::clauses).collect()
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Query::Clause(_) => false,
+            Query::And(children) | Query::Or(children) => children.is_empty(),
+        }
+    }
+
+    /// Renders the query letting `render_clause` write each clause, or drop it by returning `None`.
+    /// `separators` are the AND and OR glue, `(" ", " OR ")` in Google syntax.
+    pub fn render_with(
+        &self,
+        separators: (&str, &str),
+        render_clause: &mut dyn FnMut(&Clause) -> Option<String>,
+    ) -> String {
+        let (children, glue) = match self {
+            Query::Clause(clause) => return render_clause(clause).unwrap_or_default(),
+            Query::And(children) => (children, separators.0),
+            Query::Or(children) => (children, separators.1),
+        };
+        let and = matches!(self, Query::And(_));
+        children
+            .iter()
+            .enumerate()
+            .map(|(i, child)| match child {
+                Query::Clause(_) => match child.render_with(separators, render_clause) {
+                    inner if and && Self::runs_into_next(children, i) && !inner.starts_with('(') => {
+                        format!("({})", inner)
+                    }
+                    inner => inner,
+                },
+                _ => match child.render_with(separators, render_clause) {
+                    inner if inner.is_empty() => inner,
+                    inner => format!("({})", inner),
+                },
+            })
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(glue)
+    }
+
+    /// Whether `children[i]` is an `allin*` clause followed by a plain word, which an AND without
+    /// parentheses would read as more of its words.
+    fn runs_into_next(children: &[Query], i: usize) -> bool {
+        let takes_words = matches!(
+            &children[i],
+            Query::Clause(clause) if clause.operator.is_some_and(Operator::takes_words)
+        );
+        let next_is_word = matches!(
+            children.get(i + 1),
+            Some(Query::Clause(next))
+                if next.operator.is_none() && !next.negated && matches!(next.term, Term::Word(_) | Term::Phrase(_))
+        );
+        takes_words && next_is_word
+    }
+
+    fn fmt_child(child: &Query, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match child {
+            Query::Clause(clause) => write!(f, "{}", clause),
+            _ => write!(f, "({})", child),
+        }
+    }
+
+    fn fmt_joined(children: &[Query], glue: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, child) in children.iter().enumerate() {
+            if i > 0 {
+                f.write_str(glue)?;
+            }
+            match child {
+                Query::Clause(clause) if glue == " " && Self::runs_into_next(children, i) => {
+                    write!(f, "({})", clause)?
+                }
+                _ => Self::fmt_child(child, f)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Query::Clause(clause) => write!(f, "{}", clause),
+            Query::And(children) => Self::fmt_joined(children, " ", f),
+            Query::Or(children) => Self::fmt_joined(children, " OR ", f),
+        }
+    }
+}
+
+impl From<Clause> for Query {
+    fn from(clause: Clause) -> Self {
+        Query::Clause(clause)
+    }
+}
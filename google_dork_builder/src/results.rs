@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+
+/// A result as returned by a search, before it is stored.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SearchResult {
+    pub title: String,
+    pub link: String,
+    pub snippet: String,
+}
+
+#[derive(Default, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResultStatus {
+    #[default]
+    New,
+    Reviewed,
+    FalsePositive,
+    Finding,
+}
+
+impl ResultStatus {
+    pub const ALL: &'static [ResultStatus] = &[
+        ResultStatus::New,
+        ResultStatus::Reviewed,
+        ResultStatus::FalsePositive,
+        ResultStatus::Finding,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ResultStatus::New => "Nouveau",
+            ResultStatus::Reviewed => "Vu",
+            ResultStatus::FalsePositive => "Faux positif",
+            ResultStatus::Finding => "Trouvaille",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StoredResult {
+    pub title: String,
+    pub link: String,
+    pub snippet: String,
+    pub status: ResultStatus,
+    /// When the result first came up, as `YYYY-MM-DD HH:MM:SS`.
+    pub first_seen: String,
+    pub last_seen: String,
+}
+
+/// The results collected for one dork over all its runs.
+#[derive(Default, Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct DorkResults {
+    /// The query as written when it was last run.
+    pub query: String,
+    pub last_run: String,
+    pub results: Vec<StoredResult>,
+}
+
+impl DorkResults {
+    /// Whether the result first came up in the latest run.
+    pub fn is_new(&self, result: &StoredResult) -> bool {
+        result.first_seen == self.last_run
+    }
+}
+
+/// Results of every dork run so far, keyed by the canonical form of the dork.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResultStore {
+    dorks: BTreeMap<String, DorkResults>,
+}
+
+impl ResultStore {
+    pub fn load(path: &str) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &str) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(&self.dorks)?;
+        fs::write(path, json)
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut DorkResults> {
+        self.dorks.get_mut(key)
+    }
+
+    /// Records a run of `query`. Results already known keep their status; the others are added as
+    /// new.
+    pub fn record(&mut self, key: String, query: &str, results: Vec<SearchResult>) {
+        let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let dork = self.dorks.entry(key).or_default();
+        dork.query = query.to_string();
+        dork.last_run = now.clone();
+
+        for result in results {
+            match dork.results.iter_mut().find(|stored| stored.link == result.link) {
+                Some(stored) => {
+                    stored.title = result.title;
+                    stored.snippet = result.snippet;
+                    stored.last_seen = now.clone();
+                }
+                None => {
+                    dork.results.push(StoredResult {
+                        title: result.title,
+                        link: result.link,
+                        snippet: result.snippet,
+                        status: ResultStatus::New,
+                        first_seen: now.clone(),
+                        last_seen: now.clone(),
+                    });
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use crate::results::SearchResult;
+use base64::Engine;
+use scraper::{ElementRef, Html, Selector};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Where one engine puts each organic result in its result page.
+struct Layout {
+    engine: &'static str,
+    host: &'static str,
+    result: &'static str,
+    link: &'static str,
+    title: &'static str,
+    snippet: &'static str,
+}
+
+const LAYOUTS: &[Layout] = &[
+    Layout {
+        engine: "Google",
+        host: "google.",
+        result: "div.g, div.MjjYud",
+        link: "a[href]:has(h3)",
+        title: "h3",
+        snippet: ".VwiC3b, [data-sncf='1'], .IsZvec",
+    },
+    Layout {
+        engine: "Bing",
+        host: "bing.com",
+        result: "li.b_algo",
+        link: "h2 a[href]",
+        title: "h2",
+        snippet: ".b_caption p, p.b_lineclamp2, p.b_lineclamp3, p.b_lineclamp4",
+    },
+    Layout {
+        engine: "DuckDuckGo",
+        host: "duckduckgo.com",
+        result: "article[data-testid='result'], div.result",
+        link: "a[data-testid='result-title-a'], a.result__a",
+        title: "a[data-testid='result-title-a'], a.result__a",
+        snippet: "[data-result='snippet'], .result__snippet",
+    },
+];
+
+pub struct SerpImport {
+    pub engine: &'static str,
+    /// The `q=` the page was produced by, when the page still tells.
+    pub query: Option<String>,
+    pub results: Vec<SearchResult>,
+}
+
+#[derive(Debug)]
+pub enum ImportError {
+    Io(String),
+    NoResults,
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportError::Io(message) => write!(f, "lecture impossible : {}", message),
+            ImportError::NoResults => {
+                write!(f, "aucun résultat Google, Bing ou DuckDuckGo trouvé dans la page")
+            }
+        }
+    }
+}
+
+pub fn import_file(path: &Path) -> Result<SerpImport, ImportError> {
+    let html = fs::read_to_string(path).map_err(|err| ImportError::Io(err.to_string()))?;
+    parse(&html)
+}
+
+/// Extracts the organic results of a result page saved from the browser. The engine is told by
+/// the `saved from url=` comment browsers leave in the file, or else by the layout that matches.
+pub fn parse(html: &str) -> Result<SerpImport, ImportError> {
+    let saved_from = saved_from_url(html);
+    let document = Html::parse_document(html);
+
+    let mut layouts: Vec<&Layout> = LAYOUTS.iter().collect();
+    if let Some(url) = &saved_from {
+        layouts.sort_by_key(|layout| !url.contains(layout.host));
+    }
+    for layout in layouts {
+        let results = extract(&document, layout);
+        if !results.is_empty() {
+            let query = saved_from
+                .as_deref()
+                .and_then(|url| url_param(url, "q"))
+                .or_else(|| search_box(&document));
+            return Ok(SerpImport {
+                engine: layout.engine,
+                query,
+                results,
+            });
+        }
+    }
+    Err(ImportError::NoResults)
+}
+
+fn extract(document: &Html, layout: &Layout) -> Vec<SearchResult> {
+    let selector = |css: &str| Selector::parse(css).expect("valid selector");
+    let (result, link, title, snippet) = (
+        selector(layout.result),
+        selector(layout.link),
+        selector(layout.title),
+        selector(layout.snippet),
+    );
+
+    let mut results: Vec<SearchResult> = vec![];
+    for block in document.select(&result) {
+        let Some(anchor) = block.select(&link).next() else {
+            continue;
+        };
+        let Some(href) = anchor.attr("href").and_then(|href| target_url(href, layout)) else {
+            continue;
+        };
+        // Google nests result blocks, so the same result can be met twice.
+        if results.iter().any(|known| known.link == href) {
+            continue;
+        }
+        results.push(SearchResult {
+            title: block.select(&title).next().map(text).unwrap_or_default(),
+            link: href,
+            snippet: block.select(&snippet).next().map(text).unwrap_or_default(),
+        });
+    }
+    results
+}
+
+fn text(element: ElementRef) -> String {
+    let text: String = element.text().collect::<Vec<_>>().join(" ");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Paths of an engine's own pages: more searches, redirects, image and settings pages.
+const NAVIGATION_PATHS: &[&str] = &["/search", "/url", "/l/", "/ck/", "/images", "/imgres", "/preferences", "/webhp"];
+
+/// Unwraps the redirect links engines put in front of results, and skips the links to the
+/// engine's own pages. Results on other pages of the engine's domain, such as
+/// `support.google.com`, are kept.
+fn target_url(href: &str, layout: &Layout) -> Option<String> {
+    let href = if href.starts_with("//") { format!("https:{}", href) } else { href.to_string() };
+    let target = if href.starts_with("/url?") || href.contains("google.com/url?") {
+        url_param(&href, "q").or_else(|| url_param(&href, "url"))?
+    } else if href.contains("duckduckgo.com/l/?") {
+        url_param(&href, "uddg")?
+    } else if href.contains("bing.com/ck/a?") {
+        // Bing base64 encodes the target behind an `a1` prefix.
+        let encoded = url_param(&href, "u")?;
+        let decoded = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(encoded.strip_prefix("a1")?.trim_end_matches('='))
+            .ok()?;
+        String::from_utf8(decoded).ok()?
+    } else {
+        href
+    };
+    let mut parts = target.splitn(4, '/');
+    let host = parts.nth(2).unwrap_or_default();
+    let path = format!("/{}", parts.next().unwrap_or_default());
+    let is_navigation = host.contains(layout.host)
+        && (path == "/" || path.starts_with("/?") || NAVIGATION_PATHS.iter().any(|nav| path.starts_with(nav)));
+    (target.starts_with("http") && !is_navigation).then_some(target)
+}
+
+/// The URL in the `<!-- saved from url=(0071)https://... -->` comment.
+fn saved_from_url(html: &str) -> Option<String> {
+    let start = html.find("saved from url=(")?;
+    let rest = &html[start..];
+    let url = &rest[rest.find(')')? + 1..];
+    let end = url.find(char::is_whitespace).unwrap_or(url.len());
+    Some(url[..end].to_string())
+}
+
+/// The query left in the page's search box.
+fn search_box(document: &Html) -> Option<String> {
+    let input = Selector::parse("input[name='q'], textarea[name='q']").expect("valid selector");
+    let element = document.select(&input).next()?;
+    let value = element.attr("value").map(str::to_string).unwrap_or_else(|| text(element));
+    Some(value).filter(|value| !value.trim().is_empty())
+}
+
+/// The decoded value of `name` in the query string of `url`.
+fn url_param(url: &str, name: &str) -> Option<String> {
+    let query = url.split_once('?')?.1;
+    let query = query.split('#').next().unwrap_or_default();
+    query.split('&').find_map(|pair| {
+        let (key, value) = pair.split_once('=')?;
+        let value = value.replace('+', " ");
+        (key == name).then(|| {
+            urlencoding::decode(&value)
+                .map(|value| value.into_owned())
+                .unwrap_or(value)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_results_on_the_engine_domain_and_drops_its_own_pages() {
+        let html = r#"<!-- saved from url=(0050)https://www.google.com/search?q=site%3Agoogle.com+help -->
+            <html><body>
+            <div class="g"><a href="/url?q=https://support.google.com/websearch/answer/2466433&sa=U"><h3>Refine searches</h3></a>
+              <div class="VwiC3b">Search operators</div></div>
+            <div class="g"><a href="https://www.google.com/search?q=related"><h3>Related searches</h3></a></div>
+            <div class="g"><a href="https://www.google.com/?hl=fr"><h3>Google</h3></a></div>
+            </body></html>"#;
+        let import = parse(html).unwrap();
+        assert_eq!(import.engine, "Google");
+        assert_eq!(import.query.as_deref(), Some("site:google.com help"));
+        let links: Vec<&str> = import.results.iter().map(|result| result.link.as_str()).collect();
+        assert_eq!(links, vec!["https://support.google.com/websearch/answer/2466433"]);
+    }
+}
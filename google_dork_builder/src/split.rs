@@ -0,0 +1,145 @@
+use crate::engines::SearchEngine;
+use crate::lint::{MAX_WORDS, word_count};
+use crate::query::Query;
+
+/// Longest search URL we send; longer ones get rejected by some browsers and proxies.
+pub const MAX_URL_LENGTH: usize = 2000;
+
+pub fn fits(query: &Query, engine: &dyn SearchEngine) -> bool {
+    word_count(query) <= MAX_WORDS && engine.search_url(&query.to_string(), &[]).len() <= MAX_URL_LENGTH
+}
+
+/// The most OR-lists spread over the sub-queries at once; the longest ones are picked.
+const MAX_SPLIT_LISTS: usize = 3;
+
+/// Splits a query that is over the engine limits into sub-queries whose results add up to the
+/// original ones. Each of its longest OR-lists is cut into chunks and every combination of one
+/// chunk per list makes a sub-query; the chunk sizes are the ones giving the fewest sub-queries
+/// that all fit. Queries without an OR-list to distribute are returned unchanged.
+pub fn split(query: &Query, engine: &dyn SearchEngine) -> Vec<Query> {
+    if fits(query, engine) {
+        return vec![query.clone()];
+    }
+    let mut lists = or_lists(query);
+    if lists.is_empty() {
+        return vec![query.clone()];
+    }
+    lists.sort_by_key(|(_, alternatives)| std::cmp::Reverse(alternatives.len()));
+    lists.truncate(MAX_SPLIT_LISTS);
+
+    // One chunk size per list, tried from the fewest sub-queries up.
+    let mut plans: Vec<Vec<usize>> = vec![vec![]];
+    for (_, alternatives) in &lists {
+        plans = plans
+            .into_iter()
+            .flat_map(|plan| {
+                chunk_sizes(alternatives.len()).into_iter().map(move |size| {
+                    let mut plan = plan.clone();
+                    plan.push(size);
+                    plan
+                })
+            })
+            .collect();
+    }
+    plans.sort_by_key(|plan| {
+        lists
+            .iter()
+            .zip(plan)
+            .map(|((_, alternatives), size)| alternatives.len().div_ceil(*size))
+            .product::<usize>()
+    });
+    for plan in &plans {
+        let parts = parts(query, &lists, plan);
+        if parts.iter().all(|part| fits(part, engine)) {
+            return parts;
+        }
+    }
+
+    // Even a single alternative per list is too long: split what is left of each part.
+    parts(query, &lists, &vec![1; lists.len()])
+        .into_iter()
+        .flat_map(|part| if fits(&part, engine) { vec![part] } else { split(&part, engine) })
+        .collect()
+}
+
+/// The OR-lists that can be distributed: the query itself, or its top-level OR children, with
+/// their index in the query.
+fn or_lists(query: &Query) -> Vec<(Option<usize>, &[Query])> {
+    match query {
+        Query::Or(alternatives) => vec![(None, alternatives.as_slice())],
+        Query::And(children) => children
+            .iter()
+            .enumerate()
+            .filter_map(|(i, child)| match child {
+                Query::Or(alternatives) => Some((Some(i), alternatives.as_slice())),
+                _ => None,
+            })
+            .collect(),
+        Query::Clause(_) => vec![],
+    }
+}
+
+/// The chunk sizes worth trying for a list of `len` alternatives, one per number of chunks.
+fn chunk_sizes(len: usize) -> Vec<usize> {
+    let mut sizes: Vec<usize> = (1..=len).map(|count| len.div_ceil(count)).collect();
+    sizes.dedup();
+    sizes
+}
+
+/// Every combination of one chunk of each list, `plan` giving the chunk size of each list.
+fn parts(query: &Query, lists: &[(Option<usize>, &[Query])], plan: &[usize]) -> Vec<Query> {
+    let mut parts = vec![query.clone()];
+    for ((index, alternatives), size) in lists.iter().zip(plan) {
+        parts = parts
+            .into_iter()
+            .flat_map(|part| {
+                alternatives
+                    .chunks(*size)
+                    .map(move |chunk| replace(&part, *index, Query::any(chunk.to_vec())))
+            })
+            .collect();
+    }
+    parts
+}
+
+/// `query` with its child at `index`, or itself when there is no index, replaced.
+fn replace(query: &Query, index: Option<usize>, alternatives: Query) -> Query {
+    match (query, index) {
+        (Query::And(children), Some(index)) => {
+            let mut children = children.clone();
+            children[index] = alternatives;
+            Query::And(children)
+        }
+        _ => alternatives,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::Google;
+    use crate::parser::parse;
+    use std::collections::HashSet;
+
+    #[test]
+    fn spreads_every_or_list_over_few_queries() {
+        let list = |prefix: &str| {
+            let alternatives: Vec<String> = (0..20).map(|i| format!("{}{}", prefix, i)).collect();
+            format!("({})", alternatives.join(" OR "))
+        };
+        let query = parse(&format!("{} {}", list("a"), list("b")));
+        let parts = split(&query, &Google);
+
+        assert_eq!(parts.len(), 6);
+        assert!(parts.iter().all(|part| fits(part, &Google)));
+        let mut combinations = HashSet::new();
+        for part in &parts {
+            for a in part.clauses().iter().filter(|clause| clause.term.text().starts_with('a')) {
+                for b in part.clauses().iter().filter(|clause| clause.term.text().starts_with('b')) {
+                    combinations.insert((a.term.text(), b.term.text()));
+                }
+            }
+        }
+        assert_eq!(combinations.len(), 400);
+    }
+}
@@ -0,0 +1,121 @@
+use crate::engines::SearchEngine;
+use crate::query::{Clause, Operator, Query, Term};
+
+pub struct Translation {
+    pub query: String,
+    /// What had to be rewritten or dropped, one line per operator.
+    pub notes: Vec<String>,
+}
+
+/// Renders a query in the dialect of `engine`. Operators the engine lacks are rewritten to the
+/// closest one it has (`ext:` to `filetype:`, `allintitle:a b` to `intitle:a intitle:b`), kept as
+/// plain words when they only narrow where a word appears, and dropped otherwise.
+pub fn translate(query: &Query, engine: &dyn SearchEngine) -> Translation {
+    let mut notes = vec![];
+    let query = query.render_with(engine.separators(), &mut |clause| translate_clause(clause, engine, &mut notes));
+    notes.sort();
+    notes.dedup();
+    Translation { query, notes }
+}
+
+fn translate_clause(clause: &Clause, engine: &dyn SearchEngine, notes: &mut Vec<String>) -> Option<String> {
+    if let Some(text) = engine.rewrite(clause) {
+        return Some(text);
+    }
+    let Some(operator) = clause.operator else {
+        return Some(clause.to_string());
+    };
+    if let Some(name) = engine.operator_name(operator) {
+        let name = Some(name).filter(|_| operator.has_prefix());
+        return Some(format_clause(clause.negated, name, &clause.term));
+    }
+
+    let closest: &[Operator] = match operator {
+        Operator::Ext => &[Operator::FileType, Operator::Extension],
+        Operator::FileType => &[Operator::Extension],
+        Operator::Extension => &[Operator::Ext, Operator::FileType],
+        Operator::AllInTitle => &[Operator::InTitle],
+        Operator::AllInUrl => &[Operator::InUrl],
+        Operator::AllInText => &[Operator::InText],
+        _ => &[],
+    };
+    for closest in closest {
+        let rewritten = Clause {
+            operator: Some(*closest),
+            ..clause.clone()
+        };
+        if let Some(text) = engine.rewrite(&rewritten) {
+            notes.push(format!("{}: réécrit en {}", operator, text));
+            return Some(text);
+        }
+        if let Some(name) = engine.operator_name(*closest) {
+            notes.push(format!("{}: réécrit en {}:", operator, name));
+            return Some(format_terms(clause, Some(name), engine));
+        }
+    }
+
+    if matches!(
+        operator,
+        Operator::InUrl
+            | Operator::InTitle
+            | Operator::InText
+            | Operator::InAnchor
+            | Operator::AllInTitle
+            | Operator::AllInUrl
+            | Operator::AllInText
+    ) {
+        notes.push(format!("{}: non supporté, gardé comme terme libre", operator));
+        return Some(format_terms(clause, None, engine));
+    }
+
+    notes.push(format!("{}: non supporté, supprimé", operator));
+    None
+}
+
+/// Writes one clause per word of an `allin*` value, grouped so that it still reads as one term in
+/// an OR-list. Without an operator name, each word is a free term written the way the engine
+/// writes free terms.
+fn format_terms(clause: &Clause, name: Option<&str>, engine: &dyn SearchEngine) -> String {
+    let format = |term: &Term| {
+        let free = Clause {
+            operator: None,
+            term: term.clone(),
+            negated: clause.negated,
+        };
+        name.is_none()
+            .then(|| engine.rewrite(&free))
+            .flatten()
+            .unwrap_or_else(|| format_clause(clause.negated, name, term))
+    };
+    match &clause.term {
+        Term::Words(words) if words.len() > 1 => {
+            let parts: Vec<String> = words.iter().map(format).collect();
+            format!("({})", parts.join(engine.separators().0))
+        }
+        Term::Words(words) => words.iter().map(format).collect(),
+        term => format(term),
+    }
+}
+
+fn format_clause(negated: bool, name: Option<&str>, term: &Term) -> String {
+    let sign = if negated { "-" } else { "" };
+    match name {
+        Some(name) => format!("{}{}:{}", sign, name, term),
+        None => format!("{}{}", sign, term),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::{Bing, Google};
+    use crate::parser::parse;
+
+    #[test]
+    fn keeps_the_group_around_allin_before_a_word() {
+        for q in ["(allintitle:a b) c", "site:x.com (allinurl:admin login) \"panel\""] {
+            assert_eq!(translate(&parse(q), &Google).query, q);
+        }
+        assert_eq!(translate(&parse("(allintitle:a b) c"), &Bing).query, "(intitle:a intitle:b) c");
+    }
+}